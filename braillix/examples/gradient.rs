@@ -1,16 +1,23 @@
-use braillix::canvas::{geometry::Rect, Canvas, Style};
+use braillix::{
+    canvas::{geometry::Rect, Canvas, Style},
+    dither,
+};
 
 fn main() {
-    let segment_width = 3;
-    let segment_height = 24;
-    let mut c = Canvas::with_dot_size(65 * segment_width + 1, segment_height);
+    let width = 196;
+    let height = 24;
 
-    for b in 0..=64 {
+    // Gradients are dithered at the full precision of the matrix, so each
+    // order has one more shade than its matrix has thresholds.
+    for order in 1..=4 {
+        let shades = dither::levels(order) + 1;
+
+        let mut c = Canvas::with_dot_size(width, height).with_dither_order(order);
         c.draw(
-            Rect::new((b * segment_width, 0), (segment_width, segment_height)),
-            Style::filled_with_brightness(b),
+            Rect::new((0, 0), (width, height)),
+            Style::linear_gradient(0, dither::max_brightness(), 0.0),
         );
-    }
 
-    println!("{c}");
+        println!("order {order} ({shades} shades):\n{c}\n");
+    }
 }
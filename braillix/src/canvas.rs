@@ -3,19 +3,46 @@
 //! This module provides `Canvas` and related types. It uses a `Display`
 //! internally and provides an abstraction for drawing shapes and lines.
 
-use std::fmt;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+};
 
-use crate::display::Display;
+use crate::display::{braille_util, Display, SizeError};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+mod chart;
+
+mod compose;
 
 mod coords;
+pub use coords::Point;
 use coords::{ToCoords, ToDisplay};
 
-mod dither;
+pub(crate) mod dither;
+use dither::ThresholdMatrix;
+pub use dither::{DitherMode, MatrixError};
 
 pub mod geometry;
 
+mod image;
+
 mod style;
-pub use style::Style;
+pub use style::{BrightnessError, Gradient, Pattern, PatternError, Style};
+
+mod text;
+pub use text::Align;
+
+mod transform;
+pub use transform::{Affine2, Transform};
+
+mod viewport;
+pub use viewport::{Viewport, ViewportCanvas};
+
+mod widgets;
 
 /// Types implementing `Rasterize` can be drawn onto a `Canvas`.
 pub trait Rasterize {
@@ -23,32 +50,217 @@ pub trait Rasterize {
     fn rasterize_onto(&self, canvas: &mut Canvas, style: Style);
 }
 
+impl<T: Rasterize + ?Sized> Rasterize for &T {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        (**self).rasterize_onto(canvas, style);
+    }
+}
+
+/// Draws every shape in the slice with the same style, in order.
+impl<R: Rasterize> Rasterize for [R] {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        for object in self {
+            object.rasterize_onto(canvas, style);
+        }
+    }
+}
+
+/// Draws every shape in the `Vec` with the same style, in order.
+impl<R: Rasterize> Rasterize for Vec<R> {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        self.as_slice().rasterize_onto(canvas, style);
+    }
+}
+
+/// Allows heterogeneous shapes to be stored as `Box<dyn Rasterize>`.
+impl<T: Rasterize + ?Sized> Rasterize for Box<T> {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        (**self).rasterize_onto(canvas, style);
+    }
+}
+
+/// A shape paired with the style to draw it with. A list of these makes up
+/// a scene that can be drawn with `Canvas::draw_scene`.
+pub struct DrawCommand {
+    pub shape: Box<dyn Rasterize>,
+    pub style: Style,
+}
+
+impl DrawCommand {
+    pub fn new(shape: impl Rasterize + 'static, style: Style) -> Self {
+        Self {
+            shape: Box::new(shape),
+            style,
+        }
+    }
+}
+
 /// A canvas that offers a higher-level API on top of `Display`
 /// with drawing primitives.
+///
+/// With the `serde` feature, a canvas serializes as its `Display` along
+/// with its `size` in dots, and deserializing checks that the display is
+/// the right size to hold it. Other settings aren't serialized, so a
+/// deserialized canvas has the defaults, like one created with
+/// `with_dot_size`.
+#[derive(Clone)]
 pub struct Canvas {
     display: Display,
+    size: (usize, usize),
+    clip: Option<Bounds>,
+    track_dirty: bool,
+    dirty: Option<Bounds>,
+    xor_touched: Option<Vec<(usize, usize)>>,
+    dot_budget: Option<usize>,
+    fill_pattern: Option<Pattern>,
+    fill_gradient: Option<(Gradient, Bounds)>,
+    gamma: Option<f64>,
+    dither: ThresholdMatrix,
+}
+
+/// A normalized rectangular region, in dots. `min` is inclusive and `max`
+/// is exclusive.
+#[derive(Clone, Copy)]
+struct Bounds {
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl Bounds {
+    fn from_rect((x, y, w, h): (i32, i32, i32, i32)) -> Self {
+        let (x0, x1) = min_and_max(x, x + w);
+        let (y0, y1) = min_and_max(y, y + h);
+        Self {
+            min: (x0, y0),
+            max: (x1, y1),
+        }
+    }
+
+    fn to_rect(self) -> (i32, i32, i32, i32) {
+        let (x0, y0) = self.min;
+        let (x1, y1) = self.max;
+        (x0, y0, x1 - x0, y1 - y0)
+    }
+
+    fn contains(&self, x: i32, y: i32) -> bool {
+        (self.min.0..self.max.0).contains(&x) && (self.min.1..self.max.1).contains(&y)
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            min: (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
 }
 
 // Public API
 impl Canvas {
     /// Creates a new `Canvas` with the given dot size.
     ///
-    /// For now, the width must be a multiple of 2 and the height
-    /// must be a multiple of 4 so that it maps nicely to braille
-    /// characters.
-    ///
-    /// # Panics
-    /// This function panics if the width and height do not meet
-    /// the above constraints.
+    /// Any size is allowed. The underlying `Display` is rounded up to whole
+    /// braille cells (a multiple of 2 wide and 4 tall), and drawing is
+    /// clipped to the canvas size, so the extra dots are never set.
     pub fn with_dot_size(width: usize, height: usize) -> Self {
-        let display = Display::with_dot_size(width, height);
-        Self { display }
+        let display = Display::with_dot_size(width.next_multiple_of(2), height.next_multiple_of(4));
+        Self {
+            size: (width, height),
+            ..Self::from_display(display)
+        }
     }
 
     /// Creates a new `Canvas` with the given output (character) dimensions.
+    ///
+    /// Zero dimensions are allowed and give an empty canvas, which can be
+    /// drawn on without effect. See `try_with_output_size` to reject them.
     pub fn with_output_size(width: usize, height: usize) -> Self {
-        let display = Display::with_output_size(width, height);
-        Self { display }
+        Self::from_display(Display::with_output_size(width, height))
+    }
+
+    /// Like `with_output_size`, but returns an error if either dimension is
+    /// zero.
+    pub fn try_with_output_size(width: usize, height: usize) -> Result<Self, SizeError> {
+        Display::try_with_output_size(width, height).map(Self::from_display)
+    }
+
+    /// Creates a new `Canvas` that draws onto an existing `Display`, keeping
+    /// its dots. The canvas covers the whole display and has default
+    /// settings.
+    pub fn from_display(display: Display) -> Self {
+        Self {
+            size: display.dot_size(),
+            display,
+            clip: None,
+            track_dirty: false,
+            dirty: None,
+            xor_touched: None,
+            dot_budget: None,
+            fill_pattern: None,
+            fill_gradient: None,
+            gamma: None,
+            dither: ThresholdMatrix::Bayer(dither::DEFAULT_ORDER),
+        }
+    }
+
+    /// Sets the order of the Bayer matrix used for dithering, returning the
+    /// canvas. See `set_dither_order`.
+    pub fn with_dither_order(mut self, order: usize) -> Self {
+        self.set_dither_order(order);
+        self
+    }
+
+    /// Sets the order `n` of the (2^n) x (2^n) Bayer matrix used for
+    /// dithering. Lower orders give chunkier patterns with fewer shades.
+    /// The default is 3 (an 8x8 matrix).
+    ///
+    /// `Style` brightness values keep the same meaning at every order: they
+    /// are rescaled from `0..=64` to the `(2^n)^2` levels of the matrix.
+    /// Continuous brightness isn't rounded to those 65 values first, so
+    /// gradient fills, `plot_f64`, images, and fields get all
+    /// `(2^n)^2 + 1` shades of the matrix, and higher orders give finer
+    /// gradients.
+    ///
+    /// # Panics
+    /// This function panics if `order` is greater than `dither::MAX_ORDER` (8).
+    pub fn set_dither_order(&mut self, order: usize) {
+        assert!(
+            order <= dither::MAX_ORDER,
+            "dither order must be at most {}",
+            dither::MAX_ORDER
+        );
+        self.dither = ThresholdMatrix::Bayer(order);
+    }
+
+    /// Gets the order of the Bayer matrix used for dithering, or `None` if
+    /// a custom matrix is in use.
+    pub fn dither_order(&self) -> Option<usize> {
+        match self.dither {
+            ThresholdMatrix::Bayer(order) => Some(order),
+            ThresholdMatrix::Custom { .. } => None,
+        }
+    }
+
+    /// Sets a custom ordered-dither matrix, returning the canvas. See
+    /// `set_dither_matrix`.
+    pub fn with_dither_matrix(mut self, matrix: Vec<Vec<usize>>) -> Result<Self, MatrixError> {
+        self.set_dither_matrix(matrix)?;
+        Ok(self)
+    }
+
+    /// Replaces the Bayer matrix with a custom ordered-dither matrix given
+    /// as rows of thresholds, such as a blue-noise or clustered-dot pattern.
+    /// The matrix is tiled across the canvas.
+    ///
+    /// A matrix whose largest value is `m` has `m + 1` levels. Like with
+    /// `set_dither_order`, `Style` brightness values are rescaled to them,
+    /// and a dot is set if the rescaled brightness is above its threshold.
+    ///
+    /// Returns an error (leaving the current matrix in place) if the matrix
+    /// is empty, its rows differ in length, or it has a value above
+    /// `dither::MAX_THRESHOLD`.
+    pub fn set_dither_matrix(&mut self, matrix: Vec<Vec<usize>>) -> Result<(), MatrixError> {
+        self.dither = ThresholdMatrix::custom(matrix)?;
+        Ok(())
     }
 
     /// Returns a reference to the underlying `Display`.
@@ -56,29 +268,190 @@ impl Canvas {
         &self.display
     }
 
-    /// Returns a mutable reference to the underlying `Display`.
+    /// Returns a mutable reference to the underlying `Display`. Since
+    /// changes made through it can't be tracked, the whole canvas is marked
+    /// dirty (see `set_dirty_tracking`).
     pub fn display_mut(&mut self) -> &mut Display {
+        self.mark_all_dirty();
         &mut self.display
     }
 
+    /// Consumes the canvas, returning the underlying `Display`.
+    pub fn into_display(self) -> Display {
+        self.display
+    }
+
     /// Clears the canvas.
     pub fn clear(&mut self) {
         self.display.clear();
+        self.mark_all_dirty();
+    }
+
+    /// Fills the whole canvas with the style's fill, as if drawing a `Rect`
+    /// covering it. The outline is ignored. Solid fills (full brightness or
+    /// 0) set or clear every cell at once when nothing else, like a clip
+    /// region or dot budget, needs each dot to be drawn individually.
+    pub fn fill_background(&mut self, style: Style) {
+        let Some(brightness) = style.fill else {
+            return;
+        };
+
+        let solid = brightness == 0 || brightness >= dither::max_brightness();
+        let fast = solid
+            && style.fill_pattern.is_none()
+            && style.fill_gradient.is_none()
+            && self.clip.is_none()
+            && self.xor_touched.is_none()
+            && self.dot_budget.is_none()
+            && self.size == self.display.dot_size();
+
+        if !fast {
+            let (w, h) = self.dot_size();
+            self.draw(
+                geometry::Rect::new((0, 0), (w, h)),
+                Style {
+                    outline: None,
+                    ..style
+                },
+            );
+            return;
+        }
+
+        if brightness == 0 {
+            self.display.clear();
+        } else {
+            self.display.fill();
+        }
+        self.mark_all_dirty();
+    }
+
+    /// Resizes the canvas to the given dot size, keeping the dots that fit
+    /// in both the old and new sizes. Like with `with_dot_size`, any size is
+    /// allowed. Settings like the clip region and dither matrix are kept as
+    /// they are.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        let mut display =
+            Display::with_dot_size(width.next_multiple_of(2), height.next_multiple_of(4));
+        let (old_w, old_h) = self.dot_size();
+        for y in 0..height.min(old_h) {
+            for x in 0..width.min(old_w) {
+                if self.display.is_set(x, y) {
+                    display.set(x, y);
+                }
+            }
+        }
+
+        self.display = display;
+        self.size = (width, height);
+        self.mark_all_dirty();
+    }
+
+    /// Restricts drawing to a rectangular region given as
+    /// `(x, y, width, height)` in dots. Dots outside of the region are left
+    /// untouched by all subsequent drawing, which is useful for wipes and
+    /// viewports. Like `geometry::Rect`, negative sizes extend up or left.
+    ///
+    /// Passing `None` restores drawing to the full canvas.
+    pub fn set_clip(&mut self, rect: Option<(i32, i32, i32, i32)>) {
+        self.clip = rect.map(Bounds::from_rect);
+    }
+
+    /// Gets the current clip region as `(x, y, width, height)`, if any.
+    pub fn clip(&self) -> Option<(i32, i32, i32, i32)> {
+        self.clip.map(Bounds::to_rect)
+    }
+
+    /// Enables or disables dirty tracking. While enabled, the canvas keeps
+    /// the bounding box of every dot touched by drawing (or all of them, when
+    /// it is cleared) so that renderers can skip regions that didn't change.
+    ///
+    /// Taking `display_mut` marks the whole canvas dirty, since changes made
+    /// through it can't be tracked.
+    pub fn set_dirty_tracking(&mut self, enabled: bool) {
+        self.track_dirty = enabled;
+        if !enabled {
+            self.dirty = None;
+        }
+    }
+
+    /// Gets the region touched since dirty tracking was enabled or the
+    /// dirty bounds were last taken, as `(x, y, width, height)` in dots.
+    pub fn dirty_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        self.dirty.map(|d| {
+            let (x, y, w, h) = d.to_rect();
+            (x as usize, y as usize, w as usize, h as usize)
+        })
+    }
+
+    /// Gets the dirty bounds (see `dirty_bounds`) and resets them.
+    pub fn take_dirty_bounds(&mut self) -> Option<(usize, usize, usize, usize)> {
+        let bounds = self.dirty_bounds();
+        self.dirty = None;
+        bounds
+    }
+
+    /// Returns a new canvas with the dots flipped along the main diagonal,
+    /// so that the dot at `(x, y)` moves to `(y, x)`. The new canvas is
+    /// `dot_height` dots wide and `dot_width` dots tall.
+    ///
+    /// The new canvas keeps the dither matrix, but starts without a clip
+    /// region or dirty tracking.
+    pub fn flip_diagonal(&self) -> Canvas {
+        let (w, h) = self.size;
+        Self {
+            size: (h, w),
+            dither: self.dither.clone(),
+            ..Self::from_display(self.display.transposed())
+        }
+    }
+
+    /// Reflects the top-left quadrant of the canvas into the other three
+    /// quadrants about the canvas center, for kaleidoscope-style effects.
+    /// The other quadrants are overwritten, and the clip region is ignored.
+    pub fn mirror_quadrants(&mut self) {
+        let (w, h) = self.dot_size();
+        for y in 0..h / 2 {
+            for x in 0..w / 2 {
+                let set = self.display.is_set(x, y);
+                for (mx, my) in [(w - 1 - x, y), (x, h - 1 - y), (w - 1 - x, h - 1 - y)] {
+                    match set {
+                        true => self.display.set(mx, my),
+                        false => self.display.unset(mx, my),
+                    }
+                }
+            }
+        }
+        self.mark_all_dirty();
     }
 
     /// Gets the width of the canvas in dots.
     pub fn dot_width(&self) -> usize {
-        self.display.dot_width()
+        self.size.0
     }
 
     /// Gets the height of the canvas in dots.
     pub fn dot_height(&self) -> usize {
-        self.display.dot_height()
+        self.size.1
     }
 
     /// Gets the size (width, height) of the canvas in dots.
     pub fn dot_size(&self) -> (usize, usize) {
-        self.display.dot_size()
+        self.size
+    }
+
+    /// Maps a point given as fractions of the canvas size to dot
+    /// coordinates, so drawings can be positioned independently of the
+    /// canvas size. `(0.0, 0.0)` is the top-left corner, `(0.5, 0.5)` is the
+    /// center (the same as halving `dot_size`), and `(1.0, 1.0)` is just
+    /// past the bottom-right corner, like the far edge of a `Rect`.
+    ///
+    /// Fractions outside `0.0..=1.0` map to points off the canvas.
+    pub fn rel(&self, fx: f64, fy: f64) -> (i32, i32) {
+        let (w, h) = self.size;
+        (
+            (fx * w as f64).floor() as i32,
+            (fy * h as f64).floor() as i32,
+        )
     }
 
     /// Gets the width of the canvas in characters.
@@ -96,40 +469,345 @@ impl Canvas {
         self.display.output_size()
     }
 
+    /// Checks whether the dot at `p` is set. Points outside the canvas are
+    /// never set, so this works for hit testing without bounds checks.
+    pub fn is_set(&self, p: impl ToCoords) -> bool {
+        p.to_coords_i32()
+            .to_display(self.dot_size())
+            .is_some_and(|(x, y)| self.display.is_set(x, y))
+    }
+
+    /// Checks whether every dot of the canvas is set. Unlike
+    /// `Display::is_full`, this ignores the dots of partial cells past the
+    /// canvas size, which drawing never sets.
+    pub fn is_full(&self) -> bool {
+        let (width, height) = self.size;
+        self.display.is_rect_full(0, 0, width, height)
+    }
+
+    /// Gets the brightness of the dot at `p`. Since dots are either on or
+    /// off, this is quantized to the maximum brightness for set dots and 0
+    /// for unset dots (and points outside the canvas).
+    pub fn get_brightness(&self, p: impl ToCoords) -> usize {
+        match self.is_set(p) {
+            true => dither::max_brightness(),
+            false => 0,
+        }
+    }
+
+    /// Draws a single dot with the given raw brightness (dither threshold),
+    /// using the same dithering as shapes. A brightness of 0 always unsets
+    /// the dot and the maximum brightness always sets it.
+    pub fn plot(&mut self, p: impl ToCoords, brightness: usize) {
+        self.set_with_brightness(p.to_coords_i32(), brightness);
+    }
+
+    /// Draws a single dot with a brightness given as a float. The input is
+    /// clamped to the range `0.0..=1.0`, and isn't rounded to one of
+    /// `Style`'s 65 levels, so higher dither orders give finer shading.
+    pub fn plot_f64(&mut self, p: impl ToCoords, brightness: f64) {
+        self.set_with_level(p.to_coords_i32(), brightness.clamp(0.0, 1.0));
+    }
+
+    /// Draws a single dot at each of `points` with the outline brightness,
+    /// e.g. for scatter plots and point clouds. Nothing is drawn if the style
+    /// has no outline.
+    pub fn draw_points<P: ToCoords>(&mut self, points: impl IntoIterator<Item = P>, style: Style) {
+        let Some(brightness) = style.outline else {
+            return;
+        };
+
+        let points = points.into_iter().map(|p| p.to_coords_i32());
+        let solid = brightness == 0 || brightness >= dither::max_brightness();
+        if !solid || self.clip.is_some() {
+            self.with_gamma(&style, |canvas| {
+                for p in points {
+                    canvas.set_with_brightness(p, brightness);
+                }
+            });
+            return;
+        }
+
+        // Without dithering or clipping, every dot in bounds ends up set (or
+        // unset), so they can all be written in one batch.
+        let size = self.dot_size();
+        let dots: Vec<_> = points.filter_map(|p| p.to_display(size)).collect();
+        for &(x, y) in &dots {
+            self.mark_dirty(x, y);
+        }
+        match brightness {
+            0 => self.display.unset_many(dots),
+            _ => self.display.set_many(dots),
+        }
+    }
+
+    /// Draws an implicit shape: every dot in the `(x, y, w, h)` `bounds` for
+    /// which `inside` returns `true` is part of the shape. This renders
+    /// things like signed distance fields, fractals, and thresholded data
+    /// without implementing `Rasterize`.
+    ///
+    /// Dots of the shape that border a dot outside it (or the edge of
+    /// `bounds`) are drawn with the outline, and the rest with the fill,
+    /// including any fill pattern or gradient. Dots outside the shape are
+    /// left unchanged.
+    pub fn draw_sdf(
+        &mut self,
+        bounds: (i32, i32, i32, i32),
+        style: Style,
+        inside: impl Fn(i32, i32) -> bool,
+    ) {
+        let bounds = Bounds::from_rect(bounds);
+        let (w, h) = self.dot_size();
+        let visible = Bounds::from_rect((0, 0, w as i32, h as i32));
+        let (x0, y0) = (bounds.min.0.max(0), bounds.min.1.max(0));
+        let (x1, y1) = (
+            bounds.max.0.min(visible.max.0),
+            bounds.max.1.min(visible.max.1),
+        );
+
+        let in_shape = |x: i32, y: i32| bounds.contains(x, y) && inside(x, y);
+        let outline = style.distinguishable_outline();
+        let on_edge = |x: i32, y: i32| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .iter()
+                .any(|&(nx, ny)| !in_shape(nx, ny))
+        };
+
+        let area = bounds.to_rect();
+        for y in y0..y1 {
+            for x in x0..x1 {
+                if !in_shape(x, y) {
+                    continue;
+                }
+
+                match (outline, style.fill) {
+                    (Some(brightness), _) if on_edge(x, y) => {
+                        self.set_with_brightness((x, y), brightness);
+                    }
+                    (_, Some(brightness)) => {
+                        self.fill_with(&style, area, |canvas| {
+                            canvas.set_with_brightness((x, y), brightness);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
     /// Draw to the `Canvas` using the object's `Rasterize` implementation.
     pub fn draw(&mut self, object: impl Rasterize, style: Style) {
-        object.rasterize_onto(self, style);
+        self.with_gamma(&style, |canvas| object.rasterize_onto(canvas, style));
+    }
+
+    /// Draws each of `objects` in order with the same `style`.
+    pub fn draw_all(&mut self, objects: &[Box<dyn Rasterize>], style: Style) {
+        for object in objects {
+            self.draw(object, style);
+        }
+    }
+
+    /// Draws each command's shape with its own style, in order.
+    pub fn draw_scene(&mut self, commands: &[DrawCommand]) {
+        for command in commands {
+            self.draw(&command.shape, command.style);
+        }
+    }
+
+    /// Draws connected line segments through `points`, `width` dots thick,
+    /// with rounded joins between the segments. See `geometry::Polyline`.
+    pub fn draw_smooth_line<P: ToCoords>(&mut self, points: &[P], width: f64, style: Style) {
+        self.draw(
+            geometry::Polyline::new(points.iter().copied()).with_width(width),
+            style,
+        );
+    }
+
+    /// Draws a line from `from` to `to`. See `geometry::Line`.
+    pub fn draw_line(&mut self, from: impl ToCoords, to: impl ToCoords, style: Style) {
+        self.draw(geometry::Line::new(from, to), style);
+    }
+
+    /// Draws a rectangle with its top-left corner at `top_left` and the
+    /// given `(width, height)`. See `geometry::Rect`.
+    pub fn draw_rect(&mut self, top_left: impl ToCoords, dim: impl ToCoords, style: Style) {
+        self.draw(geometry::Rect::new(top_left, dim), style);
+    }
+
+    /// Draws a triangle with the given corners. See `geometry::Tri`.
+    pub fn draw_tri(
+        &mut self,
+        p0: impl ToCoords,
+        p1: impl ToCoords,
+        p2: impl ToCoords,
+        style: Style,
+    ) {
+        self.draw(geometry::Tri::new(p0, p1, p2), style);
+    }
+
+    /// Draw to the `Canvas` by toggling every dot the object touches
+    /// instead of setting or unsetting it. The style still selects whether
+    /// the outline and/or fill are drawn, but their brightness is ignored.
+    ///
+    /// Each dot is toggled at most once per call, so drawing the same object
+    /// twice restores the canvas exactly. This makes it easy to draw and
+    /// erase a cursor or sprite over a static background.
+    pub fn draw_xor(&mut self, object: impl Rasterize, style: Style) {
+        self.xor_touched = Some(Vec::new());
+        self.draw(object, style);
+
+        let mut touched = self.xor_touched.take().unwrap_or_default();
+        touched.sort_unstable();
+        touched.dedup();
+        for (x, y) in touched {
+            self.display.toggle(x, y);
+        }
+    }
+
+    /// Draw to the `Canvas`, but stop setting dots once `max_dots` dots have
+    /// been set. Dots that would be unset are still unset. This caps how
+    /// much work a complex shape can do, e.g. as a level-of-detail fallback
+    /// when frame time is tight.
+    pub fn draw_budgeted(&mut self, object: impl Rasterize, style: Style, max_dots: usize) {
+        self.dot_budget = Some(max_dots);
+        self.draw(object, style);
+        self.dot_budget = None;
+    }
+
+    /// Draw to the `Canvas` after mapping the object's defining points
+    /// through `transform`.
+    pub fn draw_transformed(&mut self, object: impl Transform, style: Style, transform: Affine2) {
+        self.draw(object.transform(&transform), style);
+    }
+
+    /// Draw to the `Canvas` after moving the object by `offset`, so a shape
+    /// defined around the origin can be placed anywhere. Whole-dot offsets
+    /// draw exactly what the shape would at its new position.
+    pub fn draw_at(&mut self, offset: impl ToCoords, object: impl Transform, style: Style) {
+        let (dx, dy) = offset.to_coords_f64();
+        self.draw_transformed(object, style, Affine2::translate(dx, dy));
     }
 }
 
 // Private implemetation helpers.
 impl Canvas {
     fn set_with_brightness(&mut self, p: impl ToDisplay, brightness: usize) {
+        self.set_with_level(p, brightness as f64 / dither::max_brightness() as f64);
+    }
+
+    /// Like `set_with_brightness`, but with the brightness as a fraction
+    /// from `0.0` to `1.0`, which is dithered at the full precision of the
+    /// matrix rather than `Style`'s 65 levels. NaN counts as `0.0`.
+    fn set_with_level(&mut self, p: impl ToDisplay, level: f64) {
         if let Some((x, y)) = p.to_display(self.dot_size()) {
-            const MAX_B: usize = dither::max_brightness();
-            match brightness {
+            if !self.in_clip(x, y) {
+                return;
+            }
+            self.mark_dirty(x, y);
+
+            if let Some(touched) = &mut self.xor_touched {
+                touched.push((x, y));
+                return;
+            }
+
+            let level = match &self.fill_gradient {
+                Some((gradient, bounds)) => {
+                    let (x0, y0) = bounds.min;
+                    let (x1, y1) = bounds.max;
+                    gradient.level_at(
+                        (x as f64, y as f64),
+                        (x0 as f64, y0 as f64),
+                        ((x1 - 1) as f64, (y1 - 1) as f64),
+                    )
+                }
+                None => level,
+            };
+
+            let level = match self.gamma {
+                Some(gamma) if level > 0.0 => level.min(1.0).powf(gamma),
+                _ => level,
+            };
+
+            let lit = match &self.fill_pattern {
                 // Anything with 0 brightness will end up unset, and
-                // anything above the max threshold will be set.
-                0 => self.display.unset(x, y),
-                MAX_B.. => self.display.set(x, y),
-
-                b => {
-                    if b > dither::threshold(x, y) {
-                        self.display.set(x, y);
-                    } else {
-                        self.display.unset(x, y);
-                    }
+                // anything at full brightness will be set. A fill pattern
+                // takes the place of the threshold.
+                _ if level.is_nan() || level <= 0.0 => false,
+                Some(pattern) => pattern.is_set(x, y),
+                None if level >= 1.0 => true,
+                // Equivalent to `dither::is_lit`, without rounding the level.
+                None => level * self.dither.levels() as f64 > self.dither.threshold(x, y) as f64,
+            };
+
+            if !lit {
+                self.display.unset(x, y);
+            } else if let Some(remaining) = &mut self.dot_budget {
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    self.display.set(x, y);
                 }
+            } else {
+                self.display.set(x, y);
             }
         }
     }
 
+    /// Runs `fill` with the style's fill pattern and gradient (if any)
+    /// applied. Shapes wrap the drawing of their interior in this so that
+    /// their outline is unaffected. `area` is the `(x, y, w, h)` bounding box
+    /// the gradient spans.
+    fn fill_with(
+        &mut self,
+        style: &Style,
+        area: (i32, i32, i32, i32),
+        fill: impl FnOnce(&mut Self),
+    ) {
+        let gradient = style.fill_gradient.map(|g| (g, Bounds::from_rect(area)));
+        let previous_pattern = mem::replace(&mut self.fill_pattern, style.fill_pattern);
+        let previous_gradient = mem::replace(&mut self.fill_gradient, gradient);
+        fill(self);
+        self.fill_pattern = previous_pattern;
+        self.fill_gradient = previous_gradient;
+    }
+
+    /// Runs `draw` with the style's gamma (if any) applied to every
+    /// brightness it sets. A style without a gamma keeps the current one,
+    /// so that shapes drawn as part of another inherit its gamma.
+    fn with_gamma(&mut self, style: &Style, draw: impl FnOnce(&mut Self)) {
+        let previous = self.gamma;
+        if style.gamma.is_some() {
+            self.gamma = style.gamma;
+        }
+        draw(self);
+        self.gamma = previous;
+    }
+
+    fn in_clip(&self, x: usize, y: usize) -> bool {
+        self.clip.is_none_or(|c| c.contains(x as i32, y as i32))
+    }
+
+    fn mark_all_dirty(&mut self) {
+        if self.track_dirty {
+            let (w, h) = self.dot_size();
+            self.dirty = Some(Bounds::from_rect((0, 0, w as i32, h as i32)));
+        }
+    }
+
+    fn mark_dirty(&mut self, x: usize, y: usize) {
+        if self.track_dirty {
+            let dot = Bounds::from_rect((x as i32, y as i32, 1, 1));
+            self.dirty = Some(self.dirty.map_or(dot, |d| d.union(dot)));
+        }
+    }
+
     /// If `p1` has a different x-coordinate from `p0`, it is ignored.
     fn draw_ver_line(&mut self, p0: impl ToCoords, p1: impl ToCoords, brightness: usize) {
         let (x, y0) = p0.to_coords_i32();
         let (_, y1) = p1.to_coords_i32();
 
+        // Only walk the part of the line that is on the canvas.
         let (y0, y1) = min_and_max(y0, y1);
+        let (y0, y1) = (y0.max(0), y1.min(self.dot_height() as i32 - 1));
         for y in y0..=y1 {
             self.set_with_brightness((x, y), brightness);
         }
@@ -140,13 +818,62 @@ impl Canvas {
         let (x0, y) = p0.to_coords_i32();
         let (x1, _) = p1.to_coords_i32();
 
+        // Only walk the part of the line that is on the canvas.
         let (x0, x1) = min_and_max(x0, x1);
+        let (x0, x1) = (x0.max(0), x1.min(self.dot_width() as i32 - 1));
         for x in x0..=x1 {
             self.set_with_brightness((x, y), brightness);
         }
     }
 }
 
+/// Canvases hash by the contents of their `Display` alone.
+impl Hash for Canvas {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.display.hash(state);
+    }
+}
+
+/// The serialized form of a `Canvas`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CanvasData<D> {
+    display: D,
+    size: (usize, usize),
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Canvas {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = CanvasData {
+            display: &self.display,
+            size: self.size,
+        };
+        data.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Canvas {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let CanvasData { display, size } = CanvasData::<Display>::deserialize(deserializer)?;
+        let (w, h) = size;
+        if display.dot_size() != (w.next_multiple_of(2), h.next_multiple_of(4)) {
+            let (dw, dh) = display.dot_size();
+            return Err(D::Error::custom(format_args!(
+                "a {dw} x {dh} dot display can't hold a {w} x {h} dot canvas"
+            )));
+        }
+
+        Ok(Self {
+            size,
+            ..Self::from_display(display)
+        })
+    }
+}
+
 impl From<&Canvas> for String {
     fn from(value: &Canvas) -> Self {
         String::from(&value.display)
@@ -165,6 +892,21 @@ impl fmt::Display for Canvas {
     }
 }
 
+/// Gets the braille character for an explicit dot pattern, indexed as
+/// `dots[x][y]` with `x` in `0..2` (left to right) and `y` in `0..4`
+/// (top to bottom).
+pub fn braille_char_for(dots: [[bool; 4]; 2]) -> char {
+    let mut i = 0;
+    for (x, column) in dots.iter().enumerate() {
+        for (y, &dot) in column.iter().enumerate() {
+            if dot {
+                braille_util::set_coord(&mut i, x, y);
+            }
+        }
+    }
+    braille_util::get_char(i)
+}
+
 fn min_and_max(a: i32, b: i32) -> (i32, i32) {
     if a < b {
         (a, b)
@@ -172,3 +914,523 @@ fn min_and_max(a: i32, b: i32) -> (i32, i32) {
         (b, a)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.set_clip(Some((6, 6, -4, -4)));
+        assert_eq!(canvas.clip(), Some((2, 2, 4, 4)));
+
+        canvas.draw(geometry::Rect::new((0, 0), (8, 8)), Style::filled());
+        for y in 0..8 {
+            for x in 0..8 {
+                let inside = (2..6).contains(&x) && (2..6).contains(&y);
+                assert_eq!(canvas.display().is_set(x, y), inside);
+            }
+        }
+
+        canvas.set_clip(None);
+        canvas.draw(geometry::Rect::new((0, 0), (8, 8)), Style::filled());
+        assert!(canvas.display().is_set(0, 0));
+    }
+
+    #[test]
+    fn dirty_bounds() {
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(geometry::Line::new((1, 1), (3, 3)), Style::outlined());
+        assert_eq!(canvas.dirty_bounds(), None);
+
+        canvas.set_dirty_tracking(true);
+        canvas.draw(geometry::Line::new((2, 5), (4, 3)), Style::outlined());
+        canvas.draw(geometry::Line::new((7, 9), (7, 9)), Style::outlined());
+        assert_eq!(canvas.take_dirty_bounds(), Some((2, 3, 6, 7)));
+        assert_eq!(canvas.dirty_bounds(), None);
+
+        canvas.clear();
+        assert_eq!(canvas.take_dirty_bounds(), Some((0, 0, 16, 16)));
+
+        canvas.display_mut().set(3, 3);
+        assert_eq!(canvas.take_dirty_bounds(), Some((0, 0, 16, 16)));
+    }
+
+    #[test]
+    fn draw_xor() {
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(geometry::Rect::new((0, 0), (8, 16)), Style::filled());
+        let background = canvas.display().clone();
+
+        // The circle fill touches some dots more than once, which must not
+        // cancel out within a single draw.
+        let circle = || geometry::Circle::new((8, 8), 5);
+        canvas.draw_xor(circle(), Style::filled_with_brightness(3));
+        assert!(canvas.display().is_set(10, 8));
+        assert!(!canvas.display().is_set(6, 8));
+        assert!(canvas.display().is_set(1, 8));
+
+        canvas.draw_xor(circle(), Style::filled_with_brightness(3));
+        assert_eq!(canvas.display(), &background);
+    }
+
+    #[test]
+    fn gamma() {
+        let lit = |style| {
+            let mut canvas = Canvas::with_dot_size(8, 8);
+            canvas.draw(geometry::Rect::new((0, 0), (8, 8)), style);
+            (0..8)
+                .flat_map(|y| (0..8).map(move |x| (x, y)))
+                .filter(|&(x, y)| canvas.display().is_set(x, y))
+                .count()
+        };
+
+        // Gamma curves the brightness when it is drawn, so it doesn't
+        // matter whether it is set before or after the brightness.
+        let before = Style::none().with_gamma(2.0).fill_brightness_f64(0.5);
+        let after = Style::filled_with_brightness_f64(0.5).with_gamma(2.0);
+        assert_eq!(lit(Style::filled_with_brightness_f64(0.5)), 32);
+        assert_eq!((lit(before), lit(after)), (16, 16));
+
+        // Full and zero brightness are unaffected.
+        assert_eq!(lit(Style::filled().with_gamma(2.0)), 64);
+    }
+
+    #[test]
+    fn braille_char_for() {
+        assert_eq!(super::braille_char_for([[true; 4]; 2]), '⣿');
+        assert_eq!(super::braille_char_for([[false; 4]; 2]), '⠀');
+
+        let mut top_left = [[false; 4]; 2];
+        top_left[0][0] = true;
+        assert_eq!(super::braille_char_for(top_left), '⠁');
+
+        let mut bottom_right = [[false; 4]; 2];
+        bottom_right[1][3] = true;
+        assert_eq!(super::braille_char_for(bottom_right), '⢀');
+    }
+
+    #[test]
+    fn dither_order() {
+        let half = dither::max_brightness() / 2;
+        for order in 0..=4 {
+            let mut canvas = Canvas::with_dot_size(16, 16).with_dither_order(order);
+            canvas.draw(
+                geometry::Rect::new((0, 0), (16, 16)),
+                Style::filled_with_brightness(half),
+            );
+
+            let set = (0..16)
+                .flat_map(|y| (0..16).map(move |x| (x, y)))
+                .filter(|&(x, y)| canvas.display().is_set(x, y))
+                .count();
+            let expected = if order == 0 { 256 } else { 128 };
+            assert_eq!(set, expected, "order {order}");
+        }
+
+        // Continuous brightness gets every shade of the matrix, so levels
+        // between two of `Style`'s are only told apart at higher orders.
+        let lit = |order, level| {
+            let mut canvas = Canvas::with_dot_size(16, 16).with_dither_order(order);
+            for y in 0..16 {
+                for x in 0..16 {
+                    canvas.plot_f64((x, y), level);
+                }
+            }
+            (0..16)
+                .flat_map(|y| (0..16).map(move |x| (x, y)))
+                .filter(|&(x, y)| canvas.display().is_set(x, y))
+                .count()
+        };
+        let (a, b) = (0.5 + 1.0 / 256.0, 0.5 + 2.0 / 256.0);
+        assert_eq!(lit(3, a), lit(3, b));
+        assert_eq!((lit(4, a), lit(4, b)), (129, 130));
+    }
+
+    #[test]
+    fn dither_matrix() {
+        let mut canvas = Canvas::with_dot_size(8, 4)
+            .with_dither_matrix(vec![vec![0, 1]])
+            .unwrap();
+        assert_eq!(canvas.dither_order(), None);
+
+        canvas.draw(
+            geometry::Rect::new((0, 0), (8, 4)),
+            Style::filled_with_brightness_f64(0.5),
+        );
+        for x in 0..8 {
+            assert_eq!(canvas.display().is_set(x, 2), x % 2 == 0);
+        }
+
+        assert_eq!(
+            canvas.set_dither_matrix(vec![vec![0, 1], vec![2]]),
+            Err(MatrixError::NotRectangular)
+        );
+        assert_eq!(canvas.dither_order(), None);
+
+        canvas.set_dither_order(2);
+        assert_eq!(canvas.dither_order(), Some(2));
+    }
+
+    #[test]
+    fn plot() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.plot((1, 2), dither::max_brightness());
+        canvas.plot_f64((3.4, 4.6), 1.0);
+        canvas.plot((-1, 0), dither::max_brightness());
+        assert!(canvas.display().is_set(1, 2));
+        assert!(canvas.display().is_set(3, 5));
+
+        canvas.plot((1, 2), 0);
+        assert!(!canvas.display().is_set(1, 2));
+
+        // (0, 0) has the lowest threshold, so any brightness above 0 sets it.
+        canvas.plot((0, 0), 1);
+        assert!(canvas.display().is_set(0, 0));
+        canvas.plot((1, 0), 1);
+        assert!(!canvas.display().is_set(1, 0));
+    }
+
+    #[test]
+    fn draw_budgeted() {
+        let count_set = |canvas: &Canvas| {
+            let (w, h) = canvas.dot_size();
+            (0..h)
+                .flat_map(|y| (0..w).map(move |x| (x, y)))
+                .filter(|&(x, y)| canvas.display().is_set(x, y))
+                .count()
+        };
+
+        let mut canvas = Canvas::with_dot_size(40, 8);
+        canvas.draw_budgeted(geometry::Line::new((0, 3), (39, 3)), Style::outlined(), 7);
+        assert_eq!(count_set(&canvas), 7);
+
+        canvas.clear();
+        canvas.draw_budgeted(geometry::Circle::new((20, 4), 3), Style::filled(), 5);
+        assert_eq!(count_set(&canvas), 5);
+
+        // The budget only applies to the one call.
+        canvas.draw(geometry::Line::new((0, 0), (39, 0)), Style::outlined());
+        assert_eq!(count_set(&canvas), 45);
+    }
+
+    #[test]
+    fn mirror_quadrants() {
+        let mut canvas = Canvas::with_dot_size(12, 8);
+        canvas.draw(geometry::Line::new((1, 0), (3, 2)), Style::outlined());
+        canvas.draw(geometry::Rect::new((8, 5), (2, 2)), Style::filled());
+        canvas.mirror_quadrants();
+
+        for (x, y) in [(1, 0), (2, 1), (3, 2)] {
+            assert!(canvas.display().is_set(x, y));
+            assert!(canvas.display().is_set(11 - x, y));
+            assert!(canvas.display().is_set(x, 7 - y));
+            assert!(canvas.display().is_set(11 - x, 7 - y));
+        }
+
+        // Everything outside the mirrored line was overwritten.
+        let set = (0..8)
+            .flat_map(|y| (0..12).map(move |x| (x, y)))
+            .filter(|&(x, y)| canvas.display().is_set(x, y))
+            .count();
+        assert_eq!(set, 12);
+    }
+
+    #[test]
+    fn rel() {
+        let canvas = Canvas::with_dot_size(11, 8);
+        assert_eq!(canvas.rel(0.0, 0.0), (0, 0));
+        assert_eq!(canvas.rel(0.5, 0.5), (5, 4));
+        assert_eq!(canvas.rel(1.0, 1.0), (11, 8));
+        assert_eq!(canvas.rel(0.25, 0.9), (2, 7));
+        assert_eq!(canvas.rel(-0.5, 2.0), (-6, 16));
+    }
+
+    #[test]
+    fn try_with_output_size() {
+        let canvas = Canvas::try_with_output_size(3, 2).unwrap();
+        assert_eq!(canvas.dot_size(), (6, 8));
+        assert!(Canvas::try_with_output_size(0, 2).is_err());
+    }
+
+    #[test]
+    fn draw_sdf() {
+        let disc = |x: i32, y: i32| (x - 8).pow(2) + (y - 8).pow(2) <= 25;
+
+        // A filled disc matches the dots the predicate accepts.
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw_sdf((0, 0, 16, 16), Style::filled(), disc);
+        for y in 0..16 {
+            for x in 0..16 {
+                assert_eq!(canvas.is_set((x, y)), disc(x, y), "({x}, {y})");
+            }
+        }
+
+        // Only the rim is drawn with just an outline, and the bounds clip
+        // the shape, giving it an edge along them.
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw_sdf((0, 0, 16, 8), Style::outlined(), disc);
+        assert!(canvas.is_set((4, 7)) && canvas.is_set((8, 3)));
+        assert!(canvas.is_set((8, 7)) && canvas.is_set((10, 7)));
+        assert!(!canvas.is_set((8, 5)) && !canvas.is_set((8, 8)));
+
+        // Bounds reaching off the canvas are fine.
+        let mut canvas = Canvas::with_dot_size(4, 4);
+        canvas.draw_sdf((-100, -100, 200, 200), Style::filled(), |_, _| true);
+        assert!(canvas.display().is_full());
+    }
+
+    #[test]
+    fn draw_at() {
+        use geometry::{Circle, Line, Rect};
+
+        let mut placed = Canvas::with_dot_size(16, 16);
+        let mut plain = Canvas::with_dot_size(16, 16);
+        let marker = || Rect::new((-2, -2), (5, 5));
+        for offset in [(3, 3), (12, 9)] {
+            placed.draw_at(offset, marker(), Style::outlined());
+        }
+        placed.draw_at((8, 8), Circle::new((0, 0), 3), Style::filled());
+        placed.draw_at((-1, 15), Line::new((0, 0), (17, 0)), Style::outlined());
+
+        plain.draw(Rect::new((1, 1), (5, 5)), Style::outlined());
+        plain.draw(Rect::new((10, 7), (5, 5)), Style::outlined());
+        plain.draw(Circle::new((8, 8), 3), Style::filled());
+        plain.draw(Line::new((-1, 15), (16, 15)), Style::outlined());
+        assert_eq!(placed.display(), plain.display());
+    }
+
+    #[test]
+    fn shape_shortcuts() {
+        let mut shortcuts = Canvas::with_dot_size(16, 16);
+        shortcuts.draw_line((0, 0), (15, 9), Style::outlined());
+        shortcuts.draw_rect((2, 3), (8, 5), Style::filled());
+        shortcuts.draw_tri((1, 14), (14, 14), (7, 6), Style::outlined());
+
+        let mut shapes = Canvas::with_dot_size(16, 16);
+        shapes.draw(geometry::Line::new((0, 0), (15, 9)), Style::outlined());
+        shapes.draw(geometry::Rect::new((2, 3), (8, 5)), Style::filled());
+        shapes.draw(
+            geometry::Tri::new((1, 14), (14, 14), (7, 6)),
+            Style::outlined(),
+        );
+
+        assert_eq!(shortcuts.to_string(), shapes.to_string());
+    }
+
+    #[test]
+    fn draw_all() {
+        let shapes: Vec<Box<dyn Rasterize>> = vec![
+            Box::new(geometry::Line::new((0, 0), (7, 0))),
+            Box::new(geometry::Rect::new((0, 2), (4, 2))),
+        ];
+
+        let mut canvas = Canvas::with_dot_size(8, 4);
+        canvas.draw_all(&shapes, Style::filled().outline_on());
+        assert_eq!(canvas.to_string(), "⣭⣭⠉⠉");
+
+        // Shapes can also be drawn by reference.
+        let mut by_ref = Canvas::with_dot_size(8, 4);
+        for shape in &shapes {
+            by_ref.draw(shape, Style::filled().outline_on());
+        }
+        assert_eq!(by_ref.to_string(), canvas.to_string());
+    }
+
+    #[test]
+    fn draw_slices() {
+        let lines: Vec<_> = (0..4)
+            .map(|y| geometry::Line::new((0, y * 2), (7, y * 2)))
+            .collect();
+
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.draw(lines.as_slice(), Style::outlined());
+        assert_eq!(canvas.to_string(), "⠭⠭⠭⠭\n⠭⠭⠭⠭");
+
+        let mut from_vec = Canvas::with_dot_size(8, 8);
+        from_vec.draw(lines, Style::outlined());
+        assert_eq!(from_vec.to_string(), canvas.to_string());
+    }
+
+    #[test]
+    fn draw_scene() {
+        let style = Style::outlined();
+        let scene = [
+            DrawCommand::new(geometry::Rect::new((0, 0), (4, 4)), Style::filled()),
+            DrawCommand::new(geometry::Line::new((4, 0), (7, 3)), style),
+            DrawCommand::new(geometry::Line::new((4, 0), (4, 0)), style.outline_off()),
+        ];
+
+        let mut canvas = Canvas::with_dot_size(8, 4);
+        canvas.draw_scene(&scene);
+        assert_eq!(canvas.to_string(), "⣿⣿⠐⢄");
+    }
+
+    #[test]
+    fn clone() {
+        let mut canvas = Canvas::with_dot_size(4, 4).with_dither_order(2);
+        canvas.draw_line((0, 0), (3, 3), Style::outlined());
+
+        let snapshot = canvas.clone();
+        canvas.clear();
+        assert_eq!(snapshot.to_string(), "⠑⢄");
+        assert_eq!(snapshot.dither_order(), Some(2));
+        assert_eq!(canvas.to_string(), "⠀⠀");
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let mut display = Display::with_output_size(2, 1);
+        display.set(0, 0);
+
+        let mut canvas = Canvas::from_display(display);
+        assert_eq!(canvas.dot_size(), (4, 4));
+        canvas.draw_line((3, 0), (3, 3), Style::outlined());
+
+        let display = canvas.into_display();
+        assert!(display.is_set(0, 0));
+        assert!((0..4).all(|y| display.is_set(3, y)));
+    }
+
+    #[test]
+    fn fill_background() {
+        let mut canvas = Canvas::with_dot_size(6, 8);
+        canvas.fill_background(Style::filled());
+        assert_eq!(canvas.to_string(), "⣿⣿⣿\n⣿⣿⣿");
+
+        canvas.fill_background(Style::filled().fill_off());
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀");
+
+        // Dithered and clipped fills go through the rasterizer.
+        canvas.fill_background(Style::filled_with_brightness(32));
+        let mut rect = Canvas::with_dot_size(6, 8);
+        rect.draw(
+            geometry::Rect::new((0, 0), (6, 8)),
+            Style::filled_with_brightness(32),
+        );
+        assert_eq!(canvas.to_string(), rect.to_string());
+
+        canvas.clear();
+        canvas.set_clip(Some((0, 0, 2, 4)));
+        canvas.fill_background(Style::filled());
+        assert_eq!(canvas.to_string(), "⣿⠀⠀\n⠀⠀⠀");
+
+        // A canvas with partial cells is full once its own dots are.
+        let mut partial = Canvas::with_dot_size(5, 6);
+        partial.fill_background(Style::filled());
+        assert!(partial.is_full() && !partial.display().is_full());
+        partial.plot((4, 5), 0);
+        assert!(!partial.is_full());
+    }
+
+    #[test]
+    fn resize() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.set_dirty_tracking(true);
+        canvas.draw(geometry::Line::new((0, 0), (7, 7)), Style::outlined());
+        canvas.take_dirty_bounds();
+
+        canvas.resize(4, 12);
+        assert_eq!(canvas.dot_size(), (4, 12));
+        assert_eq!(canvas.dirty_bounds(), Some((0, 0, 4, 12)));
+        for y in 0..12 {
+            for x in 0..4 {
+                assert_eq!(canvas.display().is_set(x, y), x == y, "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn edges_do_not_wrap() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.draw(geometry::Line::new((8, 0), (8, 7)), Style::outlined());
+        canvas.draw(geometry::Line::new((0, 8), (7, 8)), Style::outlined());
+        canvas.plot_f64((8.0, 3.0), 1.0);
+        assert_eq!(canvas.display(), &Display::with_dot_size(8, 8));
+    }
+
+    #[test]
+    fn draw_points() {
+        let points = [(0, 0), (3, 5), (7, 7), (8, 0), (-1, 2)];
+
+        let mut batched = Canvas::with_dot_size(8, 8);
+        batched.draw_points(points, Style::outlined());
+
+        let mut plotted = Canvas::with_dot_size(8, 8);
+        for p in points {
+            plotted.plot(p, dither::max_brightness());
+        }
+        assert_eq!(batched.display(), plotted.display());
+
+        // Dithered points match plotting them one by one.
+        let half = dither::max_brightness() / 2;
+        let grid: Vec<_> = (0..8).flat_map(|y| (0..8).map(move |x| (x, y))).collect();
+        let mut batched = Canvas::with_dot_size(8, 8);
+        batched.draw_points(grid.iter().copied(), Style::outlined_with_brightness(half));
+
+        let mut plotted = Canvas::with_dot_size(8, 8);
+        for &p in &grid {
+            plotted.plot(p, half);
+        }
+        assert_eq!(batched.display(), plotted.display());
+    }
+
+    #[test]
+    fn read_back() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.plot((2, 3), dither::max_brightness());
+
+        assert!(canvas.is_set((2, 3)));
+        assert!(canvas.is_set((2.4, 2.6)));
+        assert!(!canvas.is_set((3, 3)));
+        assert!(!canvas.is_set((-2, 3)));
+        assert!(!canvas.is_set((8, 0)));
+
+        assert_eq!(canvas.get_brightness((2, 3)), dither::max_brightness());
+        assert_eq!(canvas.get_brightness((100, 100)), 0);
+    }
+
+    #[test]
+    fn partial_cells() {
+        let mut canvas = Canvas::with_dot_size(101, 53);
+        assert_eq!(canvas.dot_size(), (101, 53));
+        assert_eq!(canvas.output_size(), (51, 14));
+
+        canvas.draw(geometry::Rect::new((0, 0), (200, 200)), Style::filled());
+        let display = canvas.display();
+        assert!(display.is_set(100, 52));
+        assert!(!display.is_set(101, 0) && !display.is_set(0, 53));
+        assert!(!canvas.is_set((101, 0)));
+
+        let flipped = canvas.flip_diagonal();
+        assert_eq!(flipped.dot_size(), (53, 101));
+
+        canvas.resize(7, 7);
+        assert_eq!(canvas.dot_size(), (7, 7));
+        assert!(canvas.display().is_set(6, 6) && !canvas.display().is_set(7, 7));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut canvas = Canvas::with_dot_size(5, 7);
+        canvas.plot((4, 6), Style::MAX_BRIGHTNESS);
+
+        let json = serde_json::to_string(&canvas).unwrap();
+        let restored: Canvas = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.dot_size(), (5, 7));
+        assert_eq!(restored.display(), canvas.display());
+
+        // Drawing still clips to the canvas, not the display.
+        let mut restored = restored;
+        restored.plot((5, 6), Style::MAX_BRIGHTNESS);
+        assert!(!restored.display().is_set(5, 6));
+
+        let bad = json.replace("[5,7]", "[9,7]");
+        let Err(err) = serde_json::from_str::<Canvas>(&bad) else {
+            panic!("a 6 x 8 dot display can't hold a 9 x 7 dot canvas");
+        };
+        assert!(err.to_string().contains("can't hold a 9 x 7 dot canvas"));
+    }
+}
@@ -0,0 +1,283 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+use alloc::{vec, vec::Vec};
+
+use super::{coords::ToCoords, geometry::Rect, Bounds, Canvas, Style};
+
+impl Canvas {
+    /// Bins `samples` into `bins` equal-width buckets spanning the range of
+    /// the samples and draws one bar per bucket along the bottom of the
+    /// canvas. Bar heights are scaled so the fullest bucket spans the full
+    /// canvas height.
+    ///
+    /// Non-finite samples are ignored. Nothing is drawn if there are no
+    /// samples left or `bins` is 0. If every sample has the same value, they
+    /// all land in the middle bucket.
+    pub fn draw_histogram(&mut self, samples: &[f64], bins: usize, style: Style) {
+        let counts = bin_samples(samples, bins);
+        let max_count = match counts.iter().max() {
+            Some(&c) if c > 0 => c,
+            _ => return,
+        };
+
+        let (w, h) = self.dot_size();
+        for (i, &count) in counts.iter().enumerate() {
+            let x0 = i * w / bins;
+            let x1 = (i + 1) * w / bins;
+            let bar_height = (count * h + max_count / 2) / max_count;
+
+            if x0 == x1 || bar_height == 0 {
+                continue;
+            }
+
+            self.draw(
+                Rect::new((x0, h - bar_height), (x1 - x0, bar_height)),
+                style,
+            );
+        }
+    }
+
+    /// Draws a grid of `cols` by `rows` cells, each `cell_w` by `cell_h`
+    /// dots, with its top-left corner at `origin`. Gridlines are one dot
+    /// wide and drawn with the outline brightness; the last ones sit at
+    /// `cols * cell_w` and `rows * cell_h` dots from the origin. Gridlines
+    /// that fall outside the canvas are skipped, and the rest are clipped to
+    /// it.
+    pub fn draw_grid(
+        &mut self,
+        origin: impl ToCoords,
+        cell_w: usize,
+        cell_h: usize,
+        cols: usize,
+        rows: usize,
+        style: Style,
+    ) {
+        let Some(brightness) = style.outline else {
+            return;
+        };
+
+        // Work in i64 so that huge grids saturate instead of overflowing.
+        let wide = |n: usize| i64::try_from(n).unwrap_or(i64::MAX);
+        let (ox, oy) = origin.to_coords_i32();
+        let (ox, oy) = (ox as i64, oy as i64);
+        let (w, h) = self.dot_size();
+        let (w, h) = (wide(w), wide(h));
+        let (cell_w, cell_h) = (wide(cell_w), wide(cell_h));
+        let (cols, rows) = (wide(cols), wide(rows));
+
+        // Endpoints are clamped to just past the canvas, which keeps them in
+        // i32 range without changing what is drawn.
+        let clamp = |v: i64, len: i64| v.clamp(-1, len) as i32;
+        let (left, right) = (
+            clamp(ox, w),
+            clamp(ox.saturating_add(cols.saturating_mul(cell_w)), w),
+        );
+        let (top, bottom) = (
+            clamp(oy, h),
+            clamp(oy.saturating_add(rows.saturating_mul(cell_h)), h),
+        );
+
+        for x in lines_on_canvas(ox, cell_w, cols, w) {
+            self.draw_ver_line((x as i32, top), (x as i32, bottom), brightness);
+        }
+        for y in lines_on_canvas(oy, cell_h, rows, h) {
+            self.draw_hor_line((left, y as i32), (right, y as i32), brightness);
+        }
+    }
+
+    /// Draws a scalar field, such as a heatmap, over the `(x, y, w, h)`
+    /// `bounds`. `values` holds `field_w * field_h` samples in row-major
+    /// order, each from `0.0` (no dots) to `1.0` (every dot), and each dot
+    /// is dithered from the field sampled bilinearly at its position. The
+    /// corner samples land exactly on the corner dots of `bounds`. Values
+    /// outside `0.0..=1.0` are clamped, and NaN samples count as `0.0`.
+    ///
+    /// # Panics
+    /// This function panics if `values` has fewer than `field_w * field_h`
+    /// samples.
+    pub fn draw_field(
+        &mut self,
+        bounds: (i32, i32, i32, i32),
+        values: &[f64],
+        field_w: usize,
+        field_h: usize,
+    ) {
+        assert!(
+            values.len() >= field_w * field_h,
+            "field has fewer than field_w * field_h values"
+        );
+        if field_w == 0 || field_h == 0 {
+            return;
+        }
+
+        let bounds = Bounds::from_rect(bounds);
+        let (w, h) = self.dot_size();
+        let (x0, y0) = (bounds.min.0.max(0), bounds.min.1.max(0));
+        let (x1, y1) = (bounds.max.0.min(w as i32), bounds.max.1.min(h as i32));
+
+        // Scales dot offsets within the bounds to field coordinates.
+        let scale = |field: usize, dots: i32| match dots {
+            ..=1 => 0.0,
+            _ => (field - 1) as f64 / (dots - 1) as f64,
+        };
+        let sx = scale(field_w, bounds.max.0 - bounds.min.0);
+        let sy = scale(field_h, bounds.max.1 - bounds.min.1);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let fx = (x - bounds.min.0) as f64 * sx;
+                let fy = (y - bounds.min.1) as f64 * sy;
+                let value = sample_bilinear(values, field_w, field_h, fx, fy);
+                self.set_with_level((x, y), value);
+            }
+        }
+    }
+}
+
+/// Samples a `field_w` by `field_h` row-major field at `(fx, fy)` by
+/// interpolating between the four nearest samples. NaN samples count as
+/// `0.0`.
+fn sample_bilinear(values: &[f64], field_w: usize, field_h: usize, fx: f64, fy: f64) -> f64 {
+    let value = |x: usize, y: usize| match values[y * field_w + x] {
+        v if v.is_nan() => 0.0,
+        v => v,
+    };
+
+    let x0 = (fx.floor() as usize).min(field_w - 1);
+    let y0 = (fy.floor() as usize).min(field_h - 1);
+    let (x1, y1) = ((x0 + 1).min(field_w - 1), (y0 + 1).min(field_h - 1));
+    let (tx, ty) = (fx - x0 as f64, fy - y0 as f64);
+
+    let top = value(x0, y0) + (value(x1, y0) - value(x0, y0)) * tx;
+    let bottom = value(x0, y1) + (value(x1, y1) - value(x0, y1)) * tx;
+    top + (bottom - top) * ty
+}
+
+/// Gets the positions `start + i * step`, for `i` in `0..=count`, that fall
+/// in `0..len`, without visiting the ones that don't.
+fn lines_on_canvas(start: i64, step: i64, count: i64, len: i64) -> impl Iterator<Item = i64> {
+    let (first, last) = if step == 0 {
+        match (0..len).contains(&start) {
+            true => (0, 0),
+            false => (1, 0),
+        }
+    } else {
+        let first = -start.div_euclid(step);
+        let last = (len - 1 - start).div_euclid(step);
+        (first.max(0), last.min(count))
+    };
+    (first..=last).map(move |i| start + i * step)
+}
+
+/// Counts how many of the finite `samples` fall into each of `bins`
+/// equal-width buckets spanning their range.
+fn bin_samples(samples: &[f64], bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    if bins == 0 {
+        return counts;
+    }
+
+    let finite = || samples.iter().copied().filter(|s| s.is_finite());
+    let min = finite().fold(f64::INFINITY, f64::min);
+    let max = finite().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    for s in finite() {
+        let bin = if range > 0.0 {
+            (((s - min) / range * bins as f64) as usize).min(bins - 1)
+        } else {
+            bins / 2
+        };
+        counts[bin] += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Measures the height of each bar by counting set dots up the
+    /// first column of its bucket.
+    fn bar_heights(canvas: &Canvas, bins: usize) -> Vec<usize> {
+        let (w, h) = canvas.dot_size();
+        (0..bins)
+            .map(|i| {
+                let x = i * w / bins;
+                (0..h)
+                    .rev()
+                    .take_while(|&y| canvas.display().is_set(x, y))
+                    .count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tallest_bin_is_mode() {
+        let samples = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0];
+        let mut canvas = Canvas::with_dot_size(10, 8);
+        canvas.draw_histogram(&samples, 5, Style::filled());
+
+        let heights = bar_heights(&canvas, 5);
+        assert_eq!(heights, vec![2, 4, 8, 4, 2]);
+    }
+
+    #[test]
+    fn grid() {
+        let mut canvas = Canvas::with_dot_size(12, 8);
+        canvas.draw_grid((-2, 1), 5, 3, 3, 2, Style::outlined());
+        assert_eq!(canvas.to_string(), "⠒⢲⠒⠒⡖⠒\n⣉⣹⣉⣉⣏⣉");
+        // Huge grids only visit the lines on the canvas.
+        let mut huge = Canvas::with_dot_size(12, 8);
+        huge.draw_grid((-2, 1), 5, 3, usize::MAX, usize::MAX, Style::outlined());
+        assert_eq!(huge.to_string(), canvas.to_string());
+
+        let mut stacked = Canvas::with_dot_size(12, 8);
+        stacked.draw_grid((3, -1), 0, usize::MAX, 100, 1, Style::outlined());
+        assert_eq!(stacked.to_string(), "⠀⢸⠀⠀⠀⠀\n⠀⢸⠀⠀⠀⠀");
+    }
+
+    #[test]
+    fn bilinear_sampling() {
+        let field = [0.0, 1.0, 0.5, f64::NAN];
+        assert_eq!(sample_bilinear(&field, 2, 2, 0.0, 0.0), 0.0);
+        assert_eq!(sample_bilinear(&field, 2, 2, 1.0, 0.0), 1.0);
+        assert_eq!(sample_bilinear(&field, 2, 2, 0.5, 0.0), 0.5);
+        assert_eq!(sample_bilinear(&field, 2, 2, 0.0, 0.5), 0.25);
+        assert_eq!(sample_bilinear(&field, 2, 2, 1.0, 1.0), 0.0);
+        assert_eq!(sample_bilinear(&field, 2, 2, 0.5, 0.5), 0.375);
+    }
+
+    #[test]
+    fn field() {
+        // Corners land on the corner dots, and the field is interpolated
+        // between them.
+        let mut canvas = Canvas::with_dot_size(16, 8);
+        canvas.draw_field((0, 0, 16, 8), &[0.0, 1.0, 0.0, 1.0], 2, 2);
+        let column_dots = |x| (0..8).filter(|&y| canvas.is_set((x, y))).count();
+        assert_eq!(column_dots(0), 0);
+        assert_eq!(column_dots(15), 8);
+        assert!((1..16).all(|x| column_dots(x - 1) <= column_dots(x) + 2));
+
+        // Bounds off the canvas and a single sample are fine.
+        let mut canvas = Canvas::with_dot_size(4, 4);
+        canvas.draw_field((-4, -4, 12, 12), &[1.0], 1, 1);
+        assert!(canvas.display().is_full());
+        canvas.draw_field((1, 1, 2, 2), &[f64::NAN], 1, 1);
+        assert!(!canvas.is_set((1, 1)) && !canvas.is_set((2, 2)));
+        assert!(canvas.is_set((0, 0)) && canvas.is_set((3, 3)));
+    }
+
+    #[test]
+    fn empty_and_equal_samples() {
+        let mut canvas = Canvas::with_dot_size(10, 8);
+        canvas.draw_histogram(&[], 5, Style::filled());
+        canvas.draw_histogram(&[1.0, 2.0], 0, Style::filled());
+        assert_eq!(bar_heights(&canvas, 5), vec![0; 5]);
+
+        canvas.draw_histogram(&[7.0; 4], 5, Style::filled());
+        assert_eq!(bar_heights(&canvas, 5), vec![0, 0, 8, 0, 0]);
+    }
+}
@@ -0,0 +1,75 @@
+use super::{dither, Canvas};
+
+impl Canvas {
+    /// Copies a grid of smaller canvases onto this one, filling rows from
+    /// left to right with `cols` canvases per row and `gap` dots between
+    /// neighbors. Every grid slot is as large as the largest canvas in
+    /// `cells`, and each canvas sits at the top-left of its slot.
+    ///
+    /// Dots are copied exactly (both set and unset), and anything that falls
+    /// outside this canvas is cropped. Nothing is drawn if `cols` is 0.
+    pub fn draw_grid_of(&mut self, cells: &[&Canvas], cols: usize, gap: usize) {
+        if cols == 0 {
+            return;
+        }
+
+        let slot_w = cells.iter().map(|c| c.dot_width()).max().unwrap_or(0);
+        let slot_h = cells.iter().map(|c| c.dot_height()).max().unwrap_or(0);
+
+        for (i, cell) in cells.iter().enumerate() {
+            let x = (i % cols) * (slot_w + gap);
+            let y = (i / cols) * (slot_h + gap);
+            self.blit(cell, (x as i32, y as i32));
+        }
+    }
+
+    /// Copies every dot of `source` onto this canvas, with the top-left
+    /// corner of `source` placed at `offset`.
+    fn blit(&mut self, source: &Canvas, offset: (i32, i32)) {
+        let (w, h) = source.dot_size();
+        for y in 0..h {
+            for x in 0..w {
+                let brightness = match source.display.is_set(x, y) {
+                    true => dither::max_brightness(),
+                    false => 0,
+                };
+                let p = (offset.0 + x as i32, offset.1 + y as i32);
+                self.set_with_brightness(p, brightness);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::{geometry::Rect, Style};
+
+    #[test]
+    fn grid_of() {
+        let quadrant = |(x, y): (i32, i32)| {
+            let mut c = Canvas::with_dot_size(4, 4);
+            c.draw(Rect::new((x, y), (1, 1)), Style::filled());
+            c
+        };
+        let cells = [
+            quadrant((0, 0)),
+            quadrant((3, 0)),
+            quadrant((0, 3)),
+            quadrant((3, 3)),
+        ];
+
+        let mut canvas = Canvas::with_dot_size(10, 12);
+        canvas.draw(Rect::new((0, 0), (10, 12)), Style::filled());
+        canvas.draw_grid_of(&cells.iter().collect::<Vec<_>>(), 2, 2);
+
+        let expected = [(0, 0), (9, 0), (0, 9), (9, 9)];
+        for y in 0..12 {
+            for x in 0..10 {
+                let in_slot = (x % 6 < 4) && (y % 6 < 4);
+                let set = expected.contains(&(x, y)) || !in_slot;
+                assert_eq!(canvas.display().is_set(x, y), set, "({x}, {y})");
+            }
+        }
+    }
+}
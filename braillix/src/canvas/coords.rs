@@ -1,3 +1,8 @@
+use core::ops::{Add, Mul, Sub};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
 /// Types implementing `ToCoords` can be used as parameters for defining
 /// shapes to draw onto a `Canvas`.
 pub trait ToCoords: Copy {
@@ -41,6 +46,172 @@ impl ToCoords for (f64, f64) {
     }
 }
 
+impl ToCoords for (f32, f32) {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self.0.round() as i32, self.1.round() as i32)
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self.0 as f64, self.1 as f64)
+    }
+}
+
+impl ToCoords for (i64, i64) {
+    /// Values outside the `i32` range saturate.
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        let saturate = |v: i64| v.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        (saturate(self.0), saturate(self.1))
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self.0 as f64, self.1 as f64)
+    }
+}
+
+impl ToCoords for (u32, u32) {
+    /// Values above `i32::MAX` saturate.
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        let saturate = |v: u32| v.min(i32::MAX as u32) as i32;
+        (saturate(self.0), saturate(self.1))
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self.0 as f64, self.1 as f64)
+    }
+}
+
+impl ToCoords for (u16, u16) {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self.0 as i32, self.1 as i32)
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self.0 as f64, self.1 as f64)
+    }
+}
+
+impl ToCoords for [usize; 2] {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self[0], self[1]).to_coords_i32()
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self[0], self[1]).to_coords_f64()
+    }
+}
+
+impl ToCoords for [i32; 2] {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self[0], self[1]).to_coords_i32()
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self[0], self[1]).to_coords_f64()
+    }
+}
+
+impl ToCoords for [f64; 2] {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self[0], self[1]).to_coords_i32()
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self[0], self[1]).to_coords_f64()
+    }
+}
+
+/// A point in dot coordinates. Any shape that accepts a tuple accepts a
+/// `Point`, and naming the fields avoids mixing up `x` and `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    #[inline]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Gets the point halfway between `self` and `other`, rounded towards
+    /// negative infinity.
+    #[inline]
+    pub fn midpoint(self, other: Point) -> Point {
+        Point::new(
+            (self.x + other.x).div_euclid(2),
+            (self.y + other.y).div_euclid(2),
+        )
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    #[inline]
+    fn from((x, y): (i32, i32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    #[inline]
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    #[inline]
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl ToCoords for Point {
+    #[inline]
+    fn to_coords_i32(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+
+    #[inline]
+    fn to_coords_f64(&self) -> (f64, f64) {
+        (self.x as f64, self.y as f64)
+    }
+}
+
 pub(super) trait ToDisplay {
     fn to_display(&self, dim: (usize, usize)) -> Option<(usize, usize)>;
 }
@@ -55,21 +226,66 @@ impl ToDisplay for (usize, usize) {
 impl ToDisplay for (i32, i32) {
     #[inline]
     fn to_display(&self, dim: (usize, usize)) -> Option<(usize, usize)> {
-        ((
-            self.0.clamp(0, dim.0 as i32),
-            (self.1.clamp(0, dim.1 as i32)),
-        ) == *self)
-            .then_some((self.0 as usize, self.1 as usize))
+        let in_range = |v: i32, max: usize| usize::try_from(v).ok().filter(|&v| v < max);
+        Some((in_range(self.0, dim.0)?, in_range(self.1, dim.1)?))
     }
 }
 
 impl ToDisplay for (f64, f64) {
     #[inline]
     fn to_display(&self, dim: (usize, usize)) -> Option<(usize, usize)> {
-        ((
-            self.0.clamp(0.0, dim.0 as f64),
-            (self.1.clamp(0.0, dim.1 as f64)),
-        ) == *self)
-            .then_some((self.0.round() as usize, self.1.round() as usize))
+        let in_range = |v: f64, max: usize| {
+            let v = v.round();
+            (v >= 0.0 && v < max as f64).then_some(v as usize)
+        };
+        Some((in_range(self.0, dim.0)?, in_range(self.1, dim.1)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!((2.5f32, -1.4f32).to_coords_i32(), (3, -1));
+        assert_eq!((2.5f32, -1.5f32).to_coords_f64(), (2.5, -1.5));
+        assert_eq!((7i64, -3i64).to_coords_i32(), (7, -3));
+        assert_eq!((i64::MAX, i64::MIN).to_coords_i32(), (i32::MAX, i32::MIN));
+        assert_eq!((4u32, u32::MAX).to_coords_i32(), (4, i32::MAX));
+        assert_eq!((80u16, 24u16).to_coords_i32(), (80, 24));
+        assert_eq!((80u16, 24u16).to_coords_f64(), (80.0, 24.0));
+
+        // Arrays match their tuple counterparts.
+        assert_eq!([3usize, 4].to_coords_i32(), (3, 4));
+        assert_eq!([-3i32, 4].to_coords_f64(), (-3.0, 4.0));
+        assert_eq!([2.5f64, -1.5].to_coords_i32(), (2.5, -1.5).to_coords_i32());
+    }
+
+    #[test]
+    fn to_display_bounds() {
+        let dim = (4, 8);
+        assert_eq!((3, 7).to_display(dim), Some((3, 7)));
+        assert_eq!((4, 0).to_display(dim), None);
+        assert_eq!((0, 8).to_display(dim), None);
+        assert_eq!((-1, 0).to_display(dim), None);
+
+        assert_eq!((3.4, 7.0).to_display(dim), Some((3, 7)));
+        assert_eq!((3.5, 0.0).to_display(dim), None);
+        assert_eq!((0.0, -0.4).to_display(dim), Some((0, 0)));
+        assert_eq!((0.0, -0.6).to_display(dim), None);
+        assert_eq!((f64::NAN, 0.0).to_display(dim), None);
+    }
+
+    #[test]
+    fn point() {
+        let a = Point::new(2, 3);
+        let b = Point::from((5, -1));
+        assert_eq!(a + b, Point::new(7, 2));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(a * 3, Point::new(6, 9));
+        assert_eq!(a.midpoint(b), Point::new(3, 1));
+        assert_eq!(<(i32, i32)>::from(b), (5, -1));
+        assert_eq!(b.to_coords_f64(), (5.0, -1.0));
     }
 }
@@ -1,24 +1,56 @@
-// TODO: figure out if/how to allow user to choose the matrix size
-const M: usize = 3;
+use alloc::{boxed::Box, vec::Vec};
+use core::{error::Error, fmt};
+
+/// How to turn continuous brightness values into on/off dots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DitherMode {
+    /// Compare each dot against the canvas's Bayer threshold matrix. This is
+    /// what shapes use, and it gives a regular, stable pattern.
+    #[default]
+    Ordered,
+    /// Diffuse each dot's quantization error onto its unvisited neighbors.
+    /// This preserves more detail in photographic content, but needs the
+    /// whole image at once.
+    FloydSteinberg,
+}
+
+/// The Bayer matrix order used by a `Canvas` unless it chooses otherwise.
+pub const DEFAULT_ORDER: usize = 3;
+
+/// The largest supported Bayer matrix order (a 256x256 matrix).
+pub const MAX_ORDER: usize = 8;
+
+/// The largest value allowed in a custom threshold matrix, which is also the
+/// largest threshold in a Bayer matrix of `MAX_ORDER`.
+pub const MAX_THRESHOLD: usize = levels(MAX_ORDER) - 1;
+
+/// Get the (2^order) x (2^order) Bayer threshold matrix value for (x, y).
+///
+/// # Panics
+///
+/// Panics if `order` is above `MAX_ORDER`.
+pub fn threshold(x: usize, y: usize, order: usize) -> usize {
+    assert!(
+        order <= MAX_ORDER,
+        "dither order must be at most {MAX_ORDER}"
+    );
 
-/// Get the (2^M) x (2^M) Bayer threshold matrix value for (x, y).
-pub fn threshold(x: usize, y: usize) -> usize {
     // TODO: is this any faster than just saving the matrix and doing a lookup?
 
-    let n = 1 << M;
+    let n = 1 << order;
     let x = x % n;
     let y = y % n;
 
     // Implementation adapted from the black magic at:
     // https://bisqwit.iki.fi/story/howto/dither/jy/
     let mut v = 0;
-    let mut mask = M.saturating_sub(1);
+    let mut mask = order.saturating_sub(1);
     let xc = x;
     let yc = x ^ y;
     let mut bit = 0;
 
     // This loop does an "interleave in reverse order" of x and (x ^ y).
-    while bit < 2 * M {
+    while bit < 2 * order {
         v |= ((xc >> mask) & 1) << bit;
         bit += 1;
 
@@ -31,11 +63,112 @@ pub fn threshold(x: usize, y: usize) -> usize {
     v
 }
 
-/// Get the highest threshold value for M.
+/// Get the number of distinct thresholds in a Bayer matrix of the given order.
+pub const fn levels(order: usize) -> usize {
+    (1 << order) * (1 << order)
+}
+
+/// Get the highest brightness value. `Style` brightness is always on the
+/// scale of the default matrix order; other orders rescale it. Canvases
+/// dither continuous brightness (gradients, images, and `plot_f64`) at the
+/// full precision of their matrix instead.
 pub const fn max_brightness() -> usize {
-    (1 << M) * (1 << M)
+    levels(DEFAULT_ORDER)
+}
+
+/// Checks if a dot should be set at `brightness`, given its threshold in a
+/// matrix with `levels` distinct thresholds.
+pub fn is_lit(brightness: usize, threshold: usize, levels: usize) -> bool {
+    // Equivalent to `brightness > threshold` when scaled to the same range.
+    brightness * levels > threshold * max_brightness()
+}
+
+/// An ordered-dither threshold matrix, tiled across the canvas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThresholdMatrix {
+    /// A Bayer matrix of the given order.
+    Bayer(usize),
+    /// A user-supplied matrix, stored row-major, along with its number of
+    /// levels.
+    Custom {
+        values: Box<[usize]>,
+        width: usize,
+        height: usize,
+        levels: usize,
+    },
+}
+
+impl ThresholdMatrix {
+    /// Validates and wraps a user-supplied matrix of rows. Values can be at
+    /// most `MAX_THRESHOLD`.
+    pub fn custom(rows: Vec<Vec<usize>>) -> Result<Self, MatrixError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(MatrixError::Empty);
+        }
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(MatrixError::NotRectangular);
+        }
+
+        let max = rows.iter().flatten().copied().max().unwrap_or(0);
+        if max > MAX_THRESHOLD {
+            return Err(MatrixError::ValueTooLarge);
+        }
+
+        Ok(Self::Custom {
+            height: rows.len(),
+            width,
+            levels: max + 1,
+            values: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Get the threshold for (x, y), tiling the matrix across the canvas.
+    pub fn threshold(&self, x: usize, y: usize) -> usize {
+        match self {
+            Self::Bayer(order) => threshold(x, y, *order),
+            Self::Custom {
+                values,
+                width,
+                height,
+                ..
+            } => values[(y % height) * width + x % width],
+        }
+    }
+
+    /// Get the number of threshold levels in the matrix. For custom
+    /// matrices, this is one more than the largest value.
+    pub fn levels(&self) -> usize {
+        match self {
+            Self::Bayer(order) => levels(*order),
+            Self::Custom { levels, .. } => *levels,
+        }
+    }
+}
+
+/// The reasons a custom threshold matrix can be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The matrix has no rows, or its first row is empty.
+    Empty,
+    /// The rows of the matrix are not all the same length.
+    NotRectangular,
+    /// A threshold matrix has a value above `MAX_THRESHOLD`.
+    ValueTooLarge,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "matrix is empty"),
+            Self::NotRectangular => write!(f, "matrix rows differ in length"),
+            Self::ValueTooLarge => write!(f, "matrix value is too large"),
+        }
+    }
 }
 
+impl Error for MatrixError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,8 +192,79 @@ mod tests {
         let dim3 = 1 << 3;
         for y in 0..dim3 {
             for x in 0..dim3 {
-                assert_eq!(threshold(x, y), BAYER_3[y][x]);
+                assert_eq!(threshold(x, y, 3), BAYER_3[y][x]);
             }
         }
     }
+
+    #[test]
+    fn other_orders() {
+        assert_eq!(threshold(5, 7, 0), 0);
+
+        let bayer_1: Vec<Vec<_>> = (0..2)
+            .map(|y| (0..2).map(|x| threshold(x, y, 1)).collect())
+            .collect();
+        assert_eq!(bayer_1, vec![vec![0, 3], vec![2, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dither order must be at most 8")]
+    fn order_too_large() {
+        threshold(0, 0, 64);
+    }
+
+    #[test]
+    fn rescaled_brightness() {
+        // At order 1, half brightness lights exactly half of the thresholds.
+        let lit = (0..levels(1))
+            .filter(|&t| is_lit(max_brightness() / 2, t, levels(1)))
+            .count();
+        assert_eq!(lit, 2);
+
+        for t in 0..levels(DEFAULT_ORDER) {
+            assert_eq!(is_lit(17, t, levels(DEFAULT_ORDER)), 17 > t);
+        }
+        // Raw brightness has 65 levels, which caps its shades at every order.
+        let shades = |order| {
+            let mut counts: Vec<_> = (0..=max_brightness())
+                .map(|b| {
+                    (0..levels(order))
+                        .filter(|&t| is_lit(b, t, levels(order)))
+                        .count()
+                })
+                .collect();
+            counts.dedup();
+            counts.len()
+        };
+        assert_eq!(shades(1), 5);
+        assert_eq!(shades(DEFAULT_ORDER), 65);
+        assert_eq!(shades(4), 65);
+    }
+
+    #[test]
+    fn custom_matrix() {
+        let matrix = ThresholdMatrix::custom(vec![vec![0, 4, 2], vec![5, 1, 3]]).unwrap();
+        assert_eq!(matrix.levels(), 6);
+        assert_eq!(matrix.threshold(4, 3), 1);
+        assert_eq!(matrix.threshold(6, 2), 0);
+
+        assert_eq!(ThresholdMatrix::custom(vec![]), Err(MatrixError::Empty));
+        assert_eq!(
+            ThresholdMatrix::custom(vec![vec![]]),
+            Err(MatrixError::Empty)
+        );
+        assert_eq!(
+            ThresholdMatrix::custom(vec![vec![0, 1], vec![2]]),
+            Err(MatrixError::NotRectangular)
+        );
+        assert_eq!(
+            ThresholdMatrix::custom(vec![vec![0, MAX_THRESHOLD + 1]]),
+            Err(MatrixError::ValueTooLarge)
+        );
+
+        let largest = ThresholdMatrix::custom(vec![vec![0, MAX_THRESHOLD]]).unwrap();
+        assert_eq!(largest.levels(), MAX_THRESHOLD + 1);
+        assert!(is_lit(max_brightness(), MAX_THRESHOLD, largest.levels()));
+        assert!(!is_lit(0, 0, largest.levels()));
+    }
 }
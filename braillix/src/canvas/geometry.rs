@@ -1,20 +1,88 @@
-use super::{coords::ToCoords, Canvas, Rasterize, Style};
+use alloc::{vec, vec::Vec};
+use core::f64::consts::{FRAC_PI_2, TAU};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+use super::{
+    coords::ToCoords,
+    transform::{Affine2, Transform},
+    Canvas, Rasterize, Style,
+};
 
 pub struct Line {
-    from: (i32, i32),
-    to: (i32, i32),
+    from: (f64, f64),
+    to: (f64, f64),
 }
 
 impl Line {
     #[inline]
     pub fn new(from: impl ToCoords, to: impl ToCoords) -> Self {
         Self {
-            from: from.to_coords_i32(),
-            to: to.to_coords_i32(),
+            from: from.to_coords_f64(),
+            to: to.to_coords_f64(),
+        }
+    }
+}
+
+impl Line {
+    /// Draws the line with Xiaolin Wu's algorithm: at every step along the
+    /// major axis, the two dots straddling the ideal line share its
+    /// brightness according to how close each one is to it. Dots whose share
+    /// rounds to 0 are left untouched.
+    fn rasterize_antialiased(&self, canvas: &mut Canvas, brightness: usize) {
+        let ((mut x0, mut y0), (mut x1, mut y1)) = (self.from, self.to);
+
+        // Walk along whichever axis the line covers more of.
+        let steep = (y1 - y0).abs() > (x1 - x0).abs();
+        if steep {
+            (x0, y0, x1, y1) = (y0, x0, y1, x1);
+        }
+        if x0 > x1 {
+            (x0, y0, x1, y1) = (x1, y1, x0, y0);
+        }
+
+        let gradient = match x1 - x0 {
+            0.0 => 0.0,
+            dx => (y1 - y0) / dx,
+        };
+
+        // Only walk the part of the major axis that is on the canvas.
+        let (w, h) = canvas.dot_size();
+        let extent = if steep { h } else { w } as f64;
+        let first = x0.round().max(-1.0) as i32;
+        let last = x1.round().min(extent) as i32;
+
+        let mut plot = |major: i32, minor: f64, coverage: f64| {
+            let b = (brightness as f64 * coverage).round() as usize;
+            if b > 0 {
+                let minor = minor as i32;
+                let p = if steep {
+                    (minor, major)
+                } else {
+                    (major, minor)
+                };
+                canvas.set_with_brightness(p, b);
+            }
+        };
+
+        for x in first..=last {
+            let y = y0 + gradient * (x as f64 - x0);
+            let (y_floor, frac) = (y.floor(), y - y.floor());
+            plot(x, y_floor, 1.0 - frac);
+            plot(x, y_floor + 1.0, frac);
         }
     }
 }
 
+impl Transform for Line {
+    type Output = Line;
+
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        Line::new(affine.apply(self.from), affine.apply(self.to))
+    }
+}
+
 impl Rasterize for Line {
     fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
         let brightness = match style.outline {
@@ -22,70 +90,399 @@ impl Rasterize for Line {
             None => return,
         };
 
-        let (x0, y0) = self.from;
-        let (x1, y1) = self.to;
+        let (x0, y0) = self.from.to_coords_i32();
+        let (x1, y1) = self.to.to_coords_i32();
+
+        if let Some(tolerance) = style.snap_axis {
+            // Snapped lines run through the midpoint of the original line.
+            // Work in i64 so that extreme coordinates can't overflow.
+            let midpoint = |a: i32, b: i32| (a as i64 + b as i64).div_euclid(2) as i32;
+            let angle = ((y1 as f64 - y0 as f64).abs()).atan2((x1 as f64 - x0 as f64).abs());
+            if angle.to_degrees() <= tolerance {
+                let y = midpoint(y0, y1);
+                canvas.draw_hor_line((x0, y), (x1, y), brightness);
+                return;
+            }
+            if angle.to_degrees() >= 90.0 - tolerance {
+                let x = midpoint(x0, x1);
+                canvas.draw_ver_line((x, y0), (x, y1), brightness);
+                return;
+            }
+        }
+
+        if style.antialias {
+            self.rasterize_antialiased(canvas, brightness);
+            return;
+        }
 
         match (x0 == x1, y0 == y1) {
             (true, true) => canvas.set_with_brightness((x0, y0), brightness),
-            (false, true) => canvas.draw_hor_line(self.from, self.to, brightness),
-            (true, false) => canvas.draw_ver_line(self.from, self.to, brightness),
+            (false, true) => canvas.draw_hor_line((x0, y0), (x1, y1), brightness),
+            (true, false) => canvas.draw_ver_line((x0, y0), (x1, y1), brightness),
             (false, false) => {
                 // Generalized Bresenham algorithm sourced from:
                 // https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm#All_cases
+                //
+                // After `i` steps along the major axis, the algorithm has taken
+                // `(2 * i * minor + major) / (2 * major)` steps along the minor
+                // axis, which lets the walk start and stop at the edges of the
+                // canvas instead of at far off-canvas endpoints.
 
-                let mut x0 = x0;
-                let mut y0 = y0;
+                let (w, h) = canvas.dot_size();
 
-                let dx = (x1 - x0).abs();
-                let sx = (x1 - x0).signum();
-                let dy = -(y1 - y0).abs();
-                let sy = (y1 - y0).signum();
-                let mut error = dx + dy;
+                // Bresenham dots are within half a dot of the ideal line, so
+                // clipping to one dot beyond the canvas keeps every visible dot.
+                let Some((c0, c1)) = clip_line(
+                    (x0 as f64, y0 as f64),
+                    (x1 as f64, y1 as f64),
+                    (-1.0, -1.0),
+                    (w as f64, h as f64),
+                ) else {
+                    return;
+                };
 
-                loop {
-                    canvas.set_with_brightness((x0, y0), brightness);
-                    let e2 = 2 * error;
+                let (x0, y0, x1, y1) = (x0 as i64, y0 as i64, x1 as i64, y1 as i64);
+                let (dx, sx) = ((x1 - x0).abs(), (x1 - x0).signum());
+                let (dy, sy) = ((y1 - y0).abs(), (y1 - y0).signum());
 
-                    if e2 >= dy {
-                        if x0 == x1 {
-                            break;
-                        }
-                        error += dy;
-                        x0 += sx;
+                let (major, minor) = if dx >= dy { (dx, dy) } else { (dy, dx) };
+                let steps = |p: (f64, f64)| {
+                    if dx >= dy {
+                        (p.0 - x0 as f64).abs()
+                    } else {
+                        (p.1 - y0 as f64).abs()
                     }
+                };
+                let (first, last) = (steps(c0).min(steps(c1)), steps(c0).max(steps(c1)));
+                let first = (first.floor() as i64).clamp(0, major);
+                let last = (last.ceil() as i64).clamp(0, major);
 
-                    if e2 <= dx {
-                        if y0 == y1 {
-                            break;
-                        }
-                        error += dx;
-                        y0 += sy;
-                    }
+                for i in first..=last {
+                    let j = ((2 * i as i128 * minor as i128 + major as i128) / (2 * major as i128))
+                        as i64;
+                    let (a, b) = if dx >= dy { (i, j) } else { (j, i) };
+                    canvas.set_with_brightness(
+                        ((x0 + sx * a) as i32, (y0 + sy * b) as i32),
+                        brightness,
+                    );
                 }
             }
         }
     }
 }
 
+/// Clips the segment from `p0` to `p1` to the box spanning `min..=max` with
+/// the Cohen-Sutherland algorithm. Returns `None` if none of it is inside.
+fn clip_line(
+    mut p0: (f64, f64),
+    mut p1: (f64, f64),
+    min: (f64, f64),
+    max: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+
+    let outcode = |(x, y): (f64, f64)| {
+        let mut code = 0;
+        if x < min.0 {
+            code |= LEFT;
+        } else if x > max.0 {
+            code |= RIGHT;
+        }
+        if y < min.1 {
+            code |= TOP;
+        } else if y > max.1 {
+            code |= BOTTOM;
+        }
+        code
+    };
+
+    let (mut code0, mut code1) = (outcode(p0), outcode(p1));
+    loop {
+        if code0 | code1 == 0 {
+            return Some((p0, p1));
+        }
+        if code0 & code1 != 0 {
+            return None;
+        }
+
+        // Move the endpoint that is outside onto the edge it is beyond.
+        let code = if code0 != 0 { code0 } else { code1 };
+        let (dx, dy) = (p1.0 - p0.0, p1.1 - p0.1);
+        let p = if code & TOP != 0 {
+            (p0.0 + dx * (min.1 - p0.1) / dy, min.1)
+        } else if code & BOTTOM != 0 {
+            (p0.0 + dx * (max.1 - p0.1) / dy, max.1)
+        } else if code & LEFT != 0 {
+            (min.0, p0.1 + dy * (min.0 - p0.0) / dx)
+        } else {
+            (max.0, p0.1 + dy * (max.0 - p0.0) / dx)
+        };
+
+        if code == code0 {
+            p0 = p;
+            code0 = outcode(p0);
+        } else {
+            p1 = p;
+            code1 = outcode(p1);
+        }
+    }
+}
+
+/// A sequence of connected line segments. Wider than one dot, each segment
+/// is stroked as a capsule (a thick line with round ends), which gives the
+/// polyline rounded joins and caps with no notches at the corners.
+///
+/// Polylines are open shapes, so only the outline is drawn.
+pub struct Polyline {
+    points: Vec<(f64, f64)>,
+    width: f64,
+}
+
+impl Polyline {
+    #[inline]
+    pub fn new(points: impl IntoIterator<Item = impl ToCoords>) -> Self {
+        Self {
+            points: points.into_iter().map(|p| p.to_coords_f64()).collect(),
+            width: 1.0,
+        }
+    }
+
+    /// Sets the stroke width in dots.
+    #[inline]
+    pub fn with_width(self, width: f64) -> Self {
+        Self { width, ..self }
+    }
+}
+
+impl Rasterize for Polyline {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        let brightness = match style.outline {
+            Some(b) => b,
+            None => return,
+        };
+
+        let mut draw_segment = |p0, p1| {
+            if self.width <= 1.0 {
+                canvas.draw(
+                    Line::new(p0, p1),
+                    Style::outlined_with_brightness(brightness),
+                );
+            } else {
+                draw_capsule(canvas, p0, p1, self.width / 2.0, brightness);
+            }
+        };
+
+        // A single point is a segment of zero length: a dot or a disc.
+        if let [p] = self.points[..] {
+            draw_segment(p, p);
+        }
+
+        for segment in self.points.windows(2) {
+            draw_segment(segment[0], segment[1]);
+        }
+    }
+}
+
+impl Transform for Polyline {
+    type Output = Polyline;
+
+    /// The points are transformed and the width is scaled the same way as a
+    /// `Circle`'s radius.
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        Polyline {
+            points: self.points.iter().map(|&p| affine.apply(p)).collect(),
+            width: self.width * affine.length_scale(),
+        }
+    }
+}
+
+/// A smooth curve through every one of `points`, drawn as a cardinal spline
+/// (a Catmull-Rom spline by default). Each span between two points bends
+/// towards its neighbors, and the first and last points are repeated so the
+/// curve starts and ends exactly on them.
+///
+/// Splines are open shapes, so only the outline is drawn.
+pub struct Spline {
+    points: Vec<(f64, f64)>,
+    tension: f64,
+}
+
+impl Spline {
+    #[inline]
+    pub fn new(points: impl IntoIterator<Item = impl ToCoords>) -> Self {
+        Self {
+            points: points.into_iter().map(|p| p.to_coords_f64()).collect(),
+            tension: 0.0,
+        }
+    }
+
+    /// Sets how tightly the curve follows its points. 0 gives a Catmull-Rom
+    /// spline, and 1 draws straight lines between the points.
+    #[inline]
+    pub fn with_tension(self, tension: f64) -> Self {
+        Self { tension, ..self }
+    }
+
+    /// Gets points along the curve, about a dot apart.
+    fn flatten(&self) -> Vec<(f64, f64)> {
+        let n = self.points.len();
+        let point = |i: usize| self.points[i.min(n - 1)];
+        let scale = (1.0 - self.tension) / 2.0;
+
+        let mut flattened = vec![self.points[0]];
+        for i in 0..n.saturating_sub(1) {
+            let (p0, p1, p2, p3) = (point(i.max(1) - 1), point(i), point(i + 1), point(i + 2));
+
+            // The tangents at either end of the span, for Hermite interpolation.
+            let m1 = ((p2.0 - p0.0) * scale, (p2.1 - p0.1) * scale);
+            let m2 = ((p3.0 - p1.0) * scale, (p3.1 - p1.1) * scale);
+
+            let (dx, dy) = (p2.0 - p1.0, p2.1 - p1.1);
+            let length = (dx * dx + dy * dy).sqrt();
+            let steps = length.ceil().max(1.0) as usize;
+            for step in 1..=steps {
+                let t = step as f64 / steps as f64;
+                let (t2, t3) = (t * t, t * t * t);
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+
+                flattened.push((
+                    h00 * p1.0 + h10 * m1.0 + h01 * p2.0 + h11 * m2.0,
+                    h00 * p1.1 + h10 * m1.1 + h01 * p2.1 + h11 * m2.1,
+                ));
+            }
+        }
+
+        flattened
+    }
+}
+
+impl Rasterize for Spline {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        let Some(brightness) = style.outline else {
+            return;
+        };
+
+        if self
+            .points
+            .iter()
+            .any(|(x, y)| !x.is_finite() || !y.is_finite())
+        {
+            return;
+        }
+        if self.points.is_empty() {
+            return;
+        }
+
+        canvas.draw(
+            Polyline::new(self.flatten()),
+            Style::outlined_with_brightness(brightness),
+        );
+    }
+}
+
+impl Transform for Spline {
+    type Output = Spline;
+
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        Spline {
+            points: self.points.iter().map(|&p| affine.apply(p)).collect(),
+            tension: self.tension,
+        }
+    }
+}
+
+/// Sets every dot within `radius` of the segment from `p0` to `p1`.
+fn draw_capsule(
+    canvas: &mut Canvas,
+    p0: (f64, f64),
+    p1: (f64, f64),
+    radius: f64,
+    brightness: usize,
+) {
+    let (dx, dy) = (p1.0 - p0.0, p1.1 - p0.1);
+    let length_sq = dx * dx + dy * dy;
+
+    let min_x = (p0.0.min(p1.0) - radius).floor() as i32;
+    let max_x = (p0.0.max(p1.0) + radius).ceil() as i32;
+    let min_y = (p0.1.min(p1.1) - radius).floor() as i32;
+    let max_y = (p0.1.max(p1.1) + radius).ceil() as i32;
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let (px, py) = (x as f64 - p0.0, y as f64 - p0.1);
+
+            // Project onto the segment to find its closest point.
+            let t = match length_sq {
+                0.0 => 0.0,
+                _ => ((px * dx + py * dy) / length_sq).clamp(0.0, 1.0),
+            };
+            let (ex, ey) = (px - t * dx, py - t * dy);
+
+            if ex * ex + ey * ey <= radius * radius {
+                canvas.set_with_brightness((x, y), brightness);
+            }
+        }
+    }
+}
+
 pub struct Rect {
-    top_left: (i32, i32),
-    dim: (i32, i32),
+    top_left: (f64, f64),
+    dim: (f64, f64),
 }
 
 impl Rect {
+    /// Creates a rectangle with its top-left corner at `top_left`, covering
+    /// `dim` (width, height) dots.
+    ///
+    /// Negative dimensions are allowed and extend the rectangle left or up
+    /// instead: a width of `-w` covers the `w` columns before `top_left`,
+    /// not including its own. See `from_corners` for building a rectangle
+    /// from any two of its corners.
     #[inline]
     pub fn new(top_left: impl ToCoords, dim: impl ToCoords) -> Self {
         Self {
-            top_left: top_left.to_coords_i32(),
-            dim: dim.to_coords_i32(),
+            top_left: top_left.to_coords_f64(),
+            dim: dim.to_coords_f64(),
         }
     }
+
+    /// Creates the rectangle with opposite corners `a` and `b`, given in any
+    /// order. Both corner dots are inside it, so e.g. the corners of a drag
+    /// selection can be passed straight through.
+    pub fn from_corners(a: impl ToCoords, b: impl ToCoords) -> Self {
+        let (ax, ay) = a.to_coords_i32();
+        let (bx, by) = b.to_coords_i32();
+        let (x0, x1) = super::min_and_max(ax, bx);
+        let (y0, y1) = super::min_and_max(ay, by);
+        Self::new((x0, y0), (x1 - x0 + 1, y1 - y0 + 1))
+    }
+
+    /// Checks whether the dot at `p` is one that a filled rectangle covers,
+    /// without drawing it.
+    pub fn contains(&self, p: impl ToCoords) -> bool {
+        let (px, py) = p.to_coords_i32();
+        let ((x, y), (w, h)) = self.dots();
+
+        let (min_x, max_x) = super::min_and_max(x, x + w);
+        let (min_y, max_y) = super::min_and_max(y, y + h);
+        (min_x..max_x).contains(&px) && (min_y..max_y).contains(&py)
+    }
+
+    /// Gets the top-left corner and dimensions, rounded to whole dots.
+    fn dots(&self) -> ((i32, i32), (i32, i32)) {
+        (self.top_left.to_coords_i32(), self.dim.to_coords_i32())
+    }
 }
 
 impl Rasterize for Rect {
     fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
-        let (x, y) = self.top_left;
-        let (w, h) = self.dim;
+        let ((x, y), (w, h)) = self.dots();
         let p1 = (x + w, y + h);
 
         let (min_x, max_x) = super::min_and_max(x, p1.0);
@@ -96,12 +493,15 @@ impl Rasterize for Rect {
         let p1 = (max_x, max_y);
 
         if let Some(brightness) = style.fill {
-            for y in p0.1..p1.1 {
-                canvas.draw(
-                    Line::new((p0.0, y), (p1.0 - 1, y)),
-                    Style::outlined_with_brightness(brightness),
-                );
-            }
+            let area = (p0.0, p0.1, p1.0 - p0.0, p1.1 - p0.1);
+            canvas.fill_with(&style, area, |canvas| {
+                for y in p0.1..p1.1 {
+                    canvas.draw(
+                        Line::new((p0.0, y), (p1.0 - 1, y)),
+                        Style::outlined_with_brightness(brightness),
+                    );
+                }
+            });
         }
 
         if let Some(brightness) = style.distinguishable_outline() {
@@ -112,19 +512,13 @@ impl Rasterize for Rect {
                 return;
             }
 
-            if w == 1 && h == 1 {
-                canvas.draw(
-                    Line::new(p0, p0),
-                    Style::outlined_with_brightness(brightness),
-                );
-                return;
-            }
-
             if w == 1 || h == 1 {
+                // A one-dot-thin rect is a single straight line (or dot).
                 canvas.draw(
-                    Line::new(p0, p1),
+                    Line::new(p0, (p1.0 - 1, p1.1 - 1)),
                     Style::outlined_with_brightness(brightness),
                 );
+                return;
             }
 
             // draw top and bottom edges
@@ -152,56 +546,258 @@ impl Rasterize for Rect {
     }
 }
 
+impl Transform for Rect {
+    type Output = Quad;
+
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        let (x, y) = self.top_left;
+        let (w, h) = self.dim;
+
+        let (x0, x1) = (x.min(x + w), x.max(x + w));
+        let (y0, y1) = (y.min(y + h), y.max(y + h));
+
+        if x0 == x1 || y0 == y1 {
+            return Quad {
+                points: [(f64::NAN, f64::NAN); 4],
+            };
+        }
+
+        // A `Rect` covers dots up to (but not including) its far corner,
+        // so the far edges are pulled in by a dot (or the whole side, if it
+        // is shorter) once transformed. The corners are only transformed
+        // before rounding, so fractional rects keep their size.
+        let origin = affine.apply((x0, y0));
+        let inset = |(px, py): (f64, f64)| {
+            let (dx, dy) = (px - origin.0, py - origin.1);
+            let length = (dx * dx + dy * dy).sqrt();
+            let t = if length > 0.0 {
+                length.min(1.0) / length
+            } else {
+                0.0
+            };
+            (dx * t, dy * t)
+        };
+        let u = inset(affine.apply((x1, y0)));
+        let v = inset(affine.apply((x0, y1)));
+
+        let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1)].map(|p| affine.apply(p));
+        let pull = [(0.0, 0.0), u, (u.0 + v.0, u.1 + v.1), v];
+        Quad {
+            points: [0, 1, 2, 3].map(|i| (corners[i].0 - pull[i].0, corners[i].1 - pull[i].1)),
+        }
+    }
+}
+
+/// A four-sided polygon, given by its corners in order around the edge.
+/// This is what a `Rect` becomes when drawn with an arbitrary `Affine2`.
+///
+/// The fill assumes the quad is convex, which is always the case for a
+/// transformed `Rect`. A quad with any non-finite corner draws nothing.
+pub struct Quad {
+    points: [(f64, f64); 4],
+}
+
+impl Quad {
+    #[inline]
+    pub fn new(p0: impl ToCoords, p1: impl ToCoords, p2: impl ToCoords, p3: impl ToCoords) -> Self {
+        Self {
+            points: [
+                p0.to_coords_f64(),
+                p1.to_coords_f64(),
+                p2.to_coords_f64(),
+                p3.to_coords_f64(),
+            ],
+        }
+    }
+}
+
+impl Rasterize for Quad {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        if self
+            .points
+            .iter()
+            .any(|(x, y)| !x.is_finite() || !y.is_finite())
+        {
+            return;
+        }
+
+        if let Some(brightness) = style.fill {
+            let area = bounding_area(&self.points);
+            canvas.fill_with(&style, area, |canvas| {
+                fill_convex_polygon(canvas, &self.points, brightness);
+            });
+        }
+
+        if let Some(brightness) = style.distinguishable_outline() {
+            for (i, &p) in self.points.iter().enumerate() {
+                let next = self.points[(i + 1) % self.points.len()];
+                canvas.draw(
+                    Line::new(p, next),
+                    Style::outlined_with_brightness(brightness),
+                );
+            }
+        }
+    }
+}
+
+impl Transform for Quad {
+    type Output = Quad;
+
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        Quad {
+            points: self.points.map(|p| affine.apply(p)),
+        }
+    }
+}
+
+/// Gets the `(x, y, w, h)` area of dots covered by the bounding box of
+/// `points`.
+fn bounding_area(points: &[(f64, f64)]) -> (i32, i32, i32, i32) {
+    let min = |f: fn(&(f64, f64)) -> f64| points.iter().map(f).fold(f64::INFINITY, f64::min);
+    let max = |f: fn(&(f64, f64)) -> f64| points.iter().map(f).fold(f64::NEG_INFINITY, f64::max);
+
+    let (x0, y0) = (min(|p| p.0).round() as i32, min(|p| p.1).round() as i32);
+    let (x1, y1) = (max(|p| p.0).round() as i32, max(|p| p.1).round() as i32);
+    (x0, y0, x1 - x0 + 1, y1 - y0 + 1)
+}
+
+/// Fills a convex polygon one horizontal span at a time.
+fn fill_convex_polygon(canvas: &mut Canvas, points: &[(f64, f64)], brightness: usize) {
+    const EPSILON: f64 = 1e-9;
+
+    let min_y = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let max_y = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+
+    for y in (min_y - EPSILON).ceil() as i32..=(max_y + EPSILON).floor() as i32 {
+        let yf = y as f64;
+        let mut span: Option<(f64, f64)> = None;
+        let mut extend = |x: f64| {
+            span = Some(span.map_or((x, x), |(l, r)| (l.min(x), r.max(x))));
+        };
+
+        for (i, &p) in points.iter().enumerate() {
+            let q = points[(i + 1) % points.len()];
+            let (lo, hi) = if p.1 < q.1 { (p, q) } else { (q, p) };
+
+            if yf < lo.1 - EPSILON || yf > hi.1 + EPSILON {
+                continue;
+            }
+
+            if hi.1 - lo.1 < EPSILON {
+                extend(lo.0);
+                extend(hi.0);
+            } else {
+                extend(lo.0 + (yf - lo.1) * (hi.0 - lo.0) / (hi.1 - lo.1));
+            }
+        }
+
+        if let Some((l, r)) = span {
+            canvas.draw_hor_line((l.round() as i32, y), (r.round() as i32, y), brightness);
+        }
+    }
+}
+
 pub struct Circle {
-    center: (i32, i32),
-    radius: i32,
+    center: (f64, f64),
+    radius: f64,
+    thickness: f64,
 }
 
 impl Circle {
+    /// Creates a circle around `center`. The center and radius are rounded
+    /// to whole dots when the circle is drawn, not before it is transformed.
     #[inline]
-    pub fn new(center: impl ToCoords, radius: i32) -> Self {
+    pub fn new(center: impl ToCoords, radius: impl Into<f64>) -> Self {
+        Self {
+            center: center.to_coords_f64(),
+            radius: radius.into(),
+            thickness: 1.0,
+        }
+    }
+
+    /// Sets how many dots thick the outline is, which is 1 by default. A
+    /// thicker outline is a band centered on the radius, reaching
+    /// `(thickness - 1) / 2` dots inward and `thickness / 2` dots outward,
+    /// which keeps small circles legible. Thick outlines are not
+    /// anti-aliased. Thicknesses below 1 are treated as 1.
+    pub fn with_thickness(self, thickness: impl Into<f64>) -> Self {
         Self {
-            center: center.to_coords_i32(),
-            radius,
+            thickness: thickness.into().max(1.0),
+            ..self
         }
     }
+
+    /// Checks whether the dot at `p` is one that a filled circle covers,
+    /// without drawing it.
+    pub fn contains(&self, p: impl ToCoords) -> bool {
+        let (px, py) = p.to_coords_i32();
+        let ((cx, cy), r) = self.dots();
+        let (dx, dy) = (px as i128 - cx as i128, py as i128 - cy as i128);
+
+        // The same quarter-dot rule as `fill_edge`, scaled by 4 so it stays
+        // exact in integers: dx² + dy² <= (r + 1/4)².
+        let edge = 4 * r as i128 + 1;
+        r >= 0 && 16 * (dx * dx + dy * dy) <= edge * edge
+    }
 }
 
 impl Rasterize for Circle {
     fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
-        // Implementation from
-        // https://en.wikipedia.org/wiki/Midpoint_circle_algorithm#Jesko%27s_Method
+        let ((cx, cy), r) = self.dots();
 
-        let (cx, cy) = self.center;
-        let mut x = self.radius;
-        let mut y = 0;
-        let mut t1 = x / 16;
+        if let Some(brightness) = style.fill {
+            let edges = (0.0, Self::fill_edge(r));
+            fill_annulus(canvas, &style, (cx, cy), r, edges, brightness);
+        }
 
-        while x >= y {
+        let Some(brightness) = style.distinguishable_outline() else {
+            return;
+        };
+
+        let thickness = (self.thickness.round() as i32).max(1);
+        if thickness > 1 {
+            let inner = r - (thickness - 1) / 2;
+            let outer = r + thickness / 2;
+            canvas.draw(
+                Ring::new((cx, cy), inner, outer),
+                Style::filled_with_brightness(brightness),
+            );
+            return;
+        }
+
+        if style.antialias {
+            Self::rasterize_antialiased_outline(canvas, (cx, cy), r, brightness, style.fill);
+            return;
+        }
+
+        for (x, y) in Self::octant(r) {
             for qx in [-1, 1] {
                 for qy in [-1, 1] {
-                    let o1 = (cx + qx * x, cy + qy * y);
-                    let o2 = (cx + qx * y, cy + qy * x);
-
-                    if let Some(brightness) = style.fill {
-                        // Draw a line from the diagonal to the point on each octant.
-                        let d = (o2.0, o1.1);
-                        canvas.draw(
-                            Line::new(d, o1),
-                            Style::outlined_with_brightness(brightness),
-                        );
-                        canvas.draw(
-                            Line::new(d, o2),
-                            Style::outlined_with_brightness(brightness),
-                        );
-                    }
-
-                    if let Some(brightness) = style.distinguishable_outline() {
-                        canvas.set_with_brightness(o1, brightness);
-                        canvas.set_with_brightness(o2, brightness);
-                    }
+                    canvas.set_with_brightness((cx + qx * x, cy + qy * y), brightness);
+                    canvas.set_with_brightness((cx + qx * y, cy + qy * x), brightness);
                 }
             }
+        }
+    }
+}
+
+impl Circle {
+    /// Gets the center and radius, rounded to whole dots.
+    fn dots(&self) -> ((i32, i32), i32) {
+        (self.center.to_coords_i32(), self.radius.round() as i32)
+    }
+
+    /// Walks one octant of a circle, from `(radius, 0)` to the diagonal.
+    /// Implementation from
+    /// https://en.wikipedia.org/wiki/Midpoint_circle_algorithm#Jesko%27s_Method
+    fn octant(radius: i32) -> Vec<(i32, i32)> {
+        let mut octant = Vec::new();
+        let mut x = radius;
+        let mut y = 0;
+        let mut t1 = x / 16;
+
+        while x >= y {
+            octant.push((x, y));
 
             y += 1;
             t1 += y;
@@ -211,9 +807,394 @@ impl Rasterize for Circle {
                 x -= 1;
             }
         }
+
+        octant
+    }
+
+    /// Gets how far from the center the fill of a circle of radius `r`
+    /// reaches. Dots within a quarter dot of the circle count as inside,
+    /// which keeps the fill inside the outline.
+    fn fill_edge(r: i32) -> f64 {
+        r as f64 + 0.25
+    }
+
+    /// Draws the outline with each dot's brightness scaled by how close it
+    /// is to the ideal circle. Inside the circle, partially covered dots are
+    /// blended with the fill (if any) instead of being dimmed towards off.
+    fn rasterize_antialiased_outline(
+        canvas: &mut Canvas,
+        (cx, cy): (i32, i32),
+        r: i32,
+        brightness: usize,
+        fill: Option<usize>,
+    ) {
+        for y in cy - r - 1..=cy + r + 1 {
+            for x in cx - r - 1..=cx + r + 1 {
+                let distance = (((x - cx).pow(2) + (y - cy).pow(2)) as f64).sqrt();
+                let coverage = antialias_coverage(distance, r as f64);
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let background = match fill {
+                    Some(f) if distance < r as f64 => f as f64,
+                    _ => 0.0,
+                };
+                let b = background + (brightness as f64 - background) * coverage;
+                let b = b.round() as usize;
+
+                if b > 0 {
+                    canvas.set_with_brightness((x, y), b);
+                }
+            }
+        }
     }
 }
 
+/// How much a dot `distance` away from the center is covered by a
+/// one-dot-wide outline of the given radius, from `0.0` to `1.0`.
+fn antialias_coverage(distance: f64, radius: f64) -> f64 {
+    (1.0 - (distance - radius).abs()).max(0.0)
+}
+
+impl Transform for Circle {
+    type Output = Circle;
+
+    /// Circles stay circles: the center is transformed and the radius is
+    /// scaled by the square root of how much the transform scales areas.
+    /// Non-uniform scaling and shearing are therefore only approximated.
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        let scale = affine.length_scale();
+        Circle::new(affine.apply(self.center), self.radius * scale)
+            .with_thickness(self.thickness * scale)
+    }
+}
+
+/// A filled pie slice: the part of a circle between two angles, bounded by
+/// two radii and the arc joining them.
+///
+/// Angles are in radians from the positive x-axis, increasing clockwise on
+/// screen (as y points down). The slice runs from `start_angle` to
+/// `end_angle` in that direction, and an end a full turn or more past the
+/// start covers the whole circle.
+pub struct Sector {
+    center: (f64, f64),
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+}
+
+impl Sector {
+    /// Creates a slice of the circle around `center`. Like a `Circle`'s,
+    /// the center and radius are rounded to whole dots when it is drawn.
+    #[inline]
+    pub fn new(
+        center: impl ToCoords,
+        radius: impl Into<f64>,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Self {
+        Self {
+            center: center.to_coords_f64(),
+            radius: radius.into(),
+            start_angle,
+            end_angle,
+        }
+    }
+
+    /// Gets the angle swept from the start to the end, up to a full turn.
+    fn sweep(&self) -> f64 {
+        let sweep = self.end_angle - self.start_angle;
+        if sweep >= TAU {
+            TAU
+        } else {
+            wrap_angle(sweep)
+        }
+    }
+
+    /// Splits the slice into convex wedges of at most a quarter turn, each
+    /// given as the normals `n` of three half-planes `n . p >= 0` that
+    /// bound it. A full turn needs no wedges.
+    fn wedges(&self, sweep: f64) -> Vec<[(f64, f64); 3]> {
+        if sweep >= TAU {
+            return Vec::new();
+        }
+
+        let count = (sweep / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / count as f64;
+        (0..count)
+            .map(|i| {
+                let start = self.start_angle + step * i as f64;
+                let (sin_a, cos_a) = start.sin_cos();
+                let (sin_b, cos_b) = (start + step).sin_cos();
+                let (sin_m, cos_m) = (start + step / 2.0).sin_cos();
+                [(-sin_a, cos_a), (sin_b, -cos_b), (cos_m, sin_m)]
+            })
+            .collect()
+    }
+}
+
+/// Narrows the span `lo..=hi` of row `y` to the part inside every half-plane
+/// `n . p >= 0`, relative to the sector's center.
+fn wedge_span(normals: &[(f64, f64); 3], y: f64, (mut lo, mut hi): (f64, f64)) -> (f64, f64) {
+    for &(nx, ny) in normals {
+        let c = ny * y;
+        if nx.abs() < 1e-12 {
+            if c < -1e-9 {
+                return (1.0, 0.0);
+            }
+        } else if nx > 0.0 {
+            lo = lo.max(-c / nx);
+        } else {
+            hi = hi.min(-c / nx);
+        }
+    }
+    (lo, hi)
+}
+
+impl Rasterize for Sector {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        let (cx, cy) = self.center.to_coords_i32();
+        let r = self.radius.round() as i32;
+        if r < 0 {
+            return;
+        }
+
+        let sweep = self.sweep();
+
+        if let Some(brightness) = style.fill {
+            let limit = (r as f64 + 0.5) * (r as f64 + 0.5);
+            let wedges = self.wedges(sweep);
+
+            // Only rows and columns on the canvas are visited.
+            let (width, height) = canvas.dot_size();
+            let (width, height) = (width as i32, height as i32);
+            let (left, right) = ((-cx - 1) as f64, (width - cx) as f64);
+
+            let size = r.saturating_mul(2).saturating_add(1);
+            let area = (cx.saturating_sub(r), cy.saturating_sub(r), size, size);
+            canvas.fill_with(&style, area, |canvas| {
+                for y in (-r).max(-cy)..=r.min(height - 1 - cy) {
+                    let yf = y as f64;
+                    let half = (limit - yf * yf).sqrt().floor();
+                    let row = (left.max(-half), right.min(half));
+
+                    let mut draw_span = |(lo, hi): (f64, f64)| {
+                        let (lo, hi) = ((lo - 1e-9).ceil(), (hi + 1e-9).floor());
+                        if lo <= hi {
+                            let (lo, hi) = (cx + lo as i32, cx + hi as i32);
+                            canvas.draw_hor_line((lo, cy + y), (hi, cy + y), brightness);
+                        }
+                    };
+
+                    if wedges.is_empty() {
+                        draw_span(row);
+                        continue;
+                    }
+                    if y == 0 {
+                        draw_span((0.0, 0.0));
+                    }
+                    for normals in &wedges {
+                        draw_span(wedge_span(normals, yf, row));
+                    }
+                }
+            });
+        }
+
+        if let Some(brightness) = style.distinguishable_outline() {
+            let center = (cx as f64, cy as f64);
+            let mut points = arc_points(center, r as f64, self.start_angle, sweep);
+            if sweep < TAU {
+                points.insert(0, center);
+                points.push(center);
+            }
+
+            canvas.draw(
+                Polyline::new(points),
+                Style::outlined_with_brightness(brightness),
+            );
+        }
+    }
+}
+
+impl Transform for Sector {
+    type Output = Sector;
+
+    /// Like circles, sectors keep their shape: the center is transformed,
+    /// the radius is scaled as for `Circle`, and the start angle follows the
+    /// transformed radius. The sweep is kept as is, so reflections and
+    /// non-uniform scaling are only approximated.
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        let (cx, cy) = self.center;
+        let center = affine.apply((cx, cy));
+
+        let (sin, cos) = self.start_angle.sin_cos();
+        let (x, y) = affine.apply((cx + cos, cy + sin));
+        let start_angle = (y - center.1).atan2(x - center.0);
+
+        Sector::new(
+            center,
+            self.radius * affine.length_scale(),
+            start_angle,
+            start_angle + self.sweep(),
+        )
+    }
+}
+
+/// The region between two concentric circles, for donut charts and radial
+/// progress bars. The fill covers every dot between the two radii, and the
+/// outline strokes both circles. With an inner radius of 0, a ring is drawn
+/// exactly like a `Circle`.
+pub struct Ring {
+    center: (f64, f64),
+    inner_radius: f64,
+    outer_radius: f64,
+}
+
+impl Ring {
+    /// Creates a ring around `center` between the two radii, given in
+    /// either order. Like a `Circle`'s, the center and radii are rounded to
+    /// whole dots when it is drawn.
+    #[inline]
+    pub fn new(
+        center: impl ToCoords,
+        inner_radius: impl Into<f64>,
+        outer_radius: impl Into<f64>,
+    ) -> Self {
+        let (a, b) = (inner_radius.into(), outer_radius.into());
+        Self {
+            center: center.to_coords_f64(),
+            inner_radius: a.min(b),
+            outer_radius: a.max(b),
+        }
+    }
+}
+
+impl Rasterize for Ring {
+    fn rasterize_onto(&self, canvas: &mut Canvas, style: Style) {
+        let (cx, cy) = self.center.to_coords_i32();
+        let inner = self.inner_radius.round() as i32;
+        let outer = self.outer_radius.round() as i32;
+
+        if inner <= 0 {
+            canvas.draw(Circle::new((cx, cy), outer), style);
+            return;
+        }
+
+        if let Some(brightness) = style.fill {
+            // Dots within half a dot of either circle count as inside.
+            let edges = (inner as f64 - 0.5, outer as f64 + 0.5);
+            fill_annulus(canvas, &style, (cx, cy), outer, edges, brightness);
+        }
+
+        if style.distinguishable_outline().is_some() {
+            let outline = Style {
+                fill: None,
+                fill_pattern: None,
+                fill_gradient: None,
+                ..style
+            };
+            canvas.draw(Circle::new((cx, cy), outer), outline);
+            canvas.draw(Circle::new((cx, cy), inner), outline);
+        }
+    }
+}
+
+/// Fills the dots around `(cx, cy)` that are at least `inner` and at most
+/// `outer` away, within the square of the given `radius`. An `inner` edge
+/// of 0 or less leaves no hole. Only rows and columns on the canvas are
+/// visited, so huge radii are cheap.
+fn fill_annulus(
+    canvas: &mut Canvas,
+    style: &Style,
+    (cx, cy): (i32, i32),
+    radius: i32,
+    (inner, outer): (f64, f64),
+    brightness: usize,
+) {
+    let outer_limit = outer * outer;
+    let inner_limit = if inner > 0.0 { inner * inner } else { 0.0 };
+
+    let (width, height) = canvas.dot_size();
+    let (width, height) = (width as i32, height as i32);
+    let (left, right) = (-(cx as f64) - 1.0, width as f64 - cx as f64);
+    let (top, bottom) = (cy.saturating_neg(), (height - 1).saturating_sub(cy));
+
+    let size = radius.saturating_mul(2).saturating_add(1);
+    let area = (
+        cx.saturating_sub(radius),
+        cy.saturating_sub(radius),
+        size,
+        size,
+    );
+    canvas.fill_with(style, area, |canvas| {
+        for y in (-radius).max(top)..=radius.min(bottom) {
+            let y2 = y as f64 * y as f64;
+            if y2 > outer_limit {
+                continue;
+            }
+
+            let row = cy + y;
+            let mut draw_span = |lo: f64, hi: f64| {
+                let (lo, hi) = (lo.max(left), hi.min(right));
+                if lo <= hi {
+                    let (lo, hi) = (cx + lo as i32, cx + hi as i32);
+                    canvas.draw_hor_line((lo, row), (hi, row), brightness);
+                }
+            };
+
+            let x_outer = (outer_limit - y2).sqrt().floor();
+            if y2 >= inner_limit {
+                draw_span(-x_outer, x_outer);
+                continue;
+            }
+
+            // The row crosses the hole, so it is split into two spans.
+            let x_inner = (inner_limit - y2).sqrt().ceil();
+            draw_span(-x_outer, -x_inner);
+            draw_span(x_inner, x_outer);
+        }
+    });
+}
+
+impl Transform for Ring {
+    type Output = Ring;
+
+    /// Rings stay rings, with both radii scaled like a `Circle`'s.
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        let scale = affine.length_scale();
+        Ring::new(
+            affine.apply(self.center),
+            self.inner_radius * scale,
+            self.outer_radius * scale,
+        )
+    }
+}
+
+/// Wraps an angle into the range `0.0..TAU`.
+fn wrap_angle(angle: f64) -> f64 {
+    let angle = angle % TAU;
+    if angle < 0.0 {
+        angle + TAU
+    } else {
+        angle
+    }
+}
+
+/// Gets points along the arc of the circle around `center`, starting at
+/// `start_angle` and sweeping `sweep` radians clockwise. Consecutive points
+/// are about a dot apart, so joining them with lines traces the arc.
+fn arc_points(center: (f64, f64), radius: f64, start_angle: f64, sweep: f64) -> Vec<(f64, f64)> {
+    let steps = (sweep * radius).ceil().max(1.0) as usize;
+    (0..=steps)
+        .map(|i| {
+            let angle = start_angle + sweep * i as f64 / steps as f64;
+            let (sin, cos) = angle.sin_cos();
+            (center.0 + radius * cos, center.1 + radius * sin)
+        })
+        .collect()
+}
+
 pub struct Tri {
     p0: (f64, f64),
     p1: (f64, f64),
@@ -229,6 +1210,25 @@ impl Tri {
             p2: p2.to_coords_f64(),
         }
     }
+
+    /// Checks whether `p` is inside the triangle or on its edges, without
+    /// drawing it.
+    pub fn contains(&self, p: impl ToCoords) -> bool {
+        const EPSILON: f64 = 1e-9;
+
+        let p = p.to_coords_f64();
+        let edge =
+            |a: (f64, f64), b: (f64, f64)| (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0);
+        let sides = [
+            edge(self.p0, self.p1),
+            edge(self.p1, self.p2),
+            edge(self.p2, self.p0),
+        ];
+
+        // The point is inside if it is on the same side of every edge,
+        // whichever way the vertices wind.
+        sides.iter().all(|&s| s >= -EPSILON) || sides.iter().all(|&s| s <= EPSILON)
+    }
 }
 
 impl Rasterize for Tri {
@@ -250,3 +1250,500 @@ impl Rasterize for Tri {
         }
     }
 }
+
+impl Transform for Tri {
+    type Output = Tri;
+
+    fn transform(&self, affine: &Affine2) -> Self::Output {
+        Tri::new(
+            affine.apply(self.p0),
+            affine.apply(self.p1),
+            affine.apply(self.p2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::Pattern;
+
+    #[test]
+    fn antialiased_line() {
+        let count_row =
+            |canvas: &Canvas, y: usize| (0..16).filter(|&x| canvas.display().is_set(x, y)).count();
+
+        // Lines through dot centers are unchanged.
+        let mut plain = Canvas::with_dot_size(16, 8);
+        let mut smooth = Canvas::with_dot_size(16, 8);
+        plain.draw(Line::new((0, 2), (15, 2)), Style::outlined());
+        smooth.draw(
+            Line::new((0, 2), (15, 2)),
+            Style::outlined().antialias(true),
+        );
+        assert_eq!(plain.display(), smooth.display());
+
+        // A line halfway between two rows lights each of them at half
+        // brightness, which dithers to half of their dots.
+        let mut canvas = Canvas::with_dot_size(16, 8);
+        canvas.draw(
+            Line::new((0.0, 2.5), (15.0, 2.5)),
+            Style::outlined().antialias(true),
+        );
+        assert_eq!((count_row(&canvas, 2), count_row(&canvas, 3)), (8, 8));
+        assert_eq!(count_row(&canvas, 1) + count_row(&canvas, 4), 0);
+    }
+
+    #[test]
+    fn snap_axis() {
+        let set_rows = |canvas: &Canvas| {
+            (0..16)
+                .filter(|&y| (0..64).any(|x| canvas.display().is_set(x, y)))
+                .collect::<Vec<_>>()
+        };
+
+        // About 1 degree off horizontal.
+        let line = || Line::new((2, 4), (59, 5));
+
+        let mut canvas = Canvas::with_dot_size(64, 16);
+        canvas.draw(line(), Style::outlined());
+        assert_eq!(set_rows(&canvas), vec![4, 5]);
+
+        let mut canvas = Canvas::with_dot_size(64, 16);
+        canvas.draw(line(), Style::outlined().snap_axis(2.0));
+        assert_eq!(set_rows(&canvas), vec![4]);
+        assert!((2..=59).all(|x| canvas.display().is_set(x, 4)));
+
+        // Steep lines snap to vertical.
+        let mut canvas = Canvas::with_dot_size(64, 16);
+        canvas.draw(
+            Line::new((10, 0), (11, 15)),
+            Style::outlined().snap_axis(5.0),
+        );
+        assert!((0..16).all(|y| canvas.display().is_set(10, y)));
+        assert!((0..16).all(|y| !canvas.display().is_set(11, y)));
+    }
+
+    #[test]
+    fn fill_pattern() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.draw(
+            Rect::new((0, 0), (8, 8)),
+            Style::outlined()
+                .fill_on()
+                .fill_pattern(Pattern::checkerboard()),
+        );
+
+        for y in 0..8 {
+            for x in 0..8 {
+                let edge = x == 0 || y == 0 || x == 7 || y == 7;
+                let expected = edge || (x + y) % 2 == 0;
+                assert_eq!(canvas.display().is_set(x, y), expected, "({x}, {y})");
+            }
+        }
+
+        // A pattern still clears everything when the fill is off.
+        canvas.draw(
+            Rect::new((0, 0), (8, 8)),
+            Style::filled()
+                .fill_off()
+                .fill_pattern(Pattern::checkerboard()),
+        );
+        assert!((0..8).all(|y| (0..8).all(|x| !canvas.display().is_set(x, y))));
+    }
+
+    #[test]
+    fn gradient_fill() {
+        let column = |canvas: &Canvas, x| (0..8).filter(|&y| canvas.display().is_set(x, y)).count();
+
+        let mut canvas = Canvas::with_dot_size(16, 8);
+        canvas.draw(
+            Rect::new((0, 0), (16, 8)),
+            Style::linear_gradient(0, 64, 0.0),
+        );
+        assert_eq!(column(&canvas, 0), 0);
+        assert_eq!(column(&canvas, 15), 8);
+        let left: usize = (0..8).map(|x| column(&canvas, x)).sum();
+        let right: usize = (8..16).map(|x| column(&canvas, x)).sum();
+        assert!(left < right);
+
+        // Reversing the angle reverses the gradient.
+        let mut canvas = Canvas::with_dot_size(16, 8);
+        canvas.draw(
+            Rect::new((0, 0), (16, 8)),
+            Style::linear_gradient(0, 64, 180.0),
+        );
+        assert_eq!(column(&canvas, 0), 8);
+        assert_eq!(column(&canvas, 15), 0);
+    }
+
+    #[test]
+    fn clipped_line() {
+        // Drawing on a larger canvas with everything shifted by `offset`
+        // gives the unclipped result to compare against.
+        let offset = 40;
+        let lines = [
+            ((-30, -7), (50, 20)),
+            ((10, -35), (3, 45)),
+            ((-20, 15), (-1, -3)),
+            ((55, 2), (-12, 9)),
+        ];
+
+        for (p0, p1) in lines {
+            let mut clipped = Canvas::with_dot_size(16, 16);
+            clipped.draw(Line::new(p0, p1), Style::outlined());
+
+            let mut full = Canvas::with_dot_size(96, 96);
+            full.draw(
+                Line::new(
+                    (p0.0 + offset, p0.1 + offset),
+                    (p1.0 + offset, p1.1 + offset),
+                ),
+                Style::outlined(),
+            );
+
+            for y in 0..16 {
+                for x in 0..16 {
+                    assert_eq!(
+                        clipped.display().is_set(x, y),
+                        full.display()
+                            .is_set(x + offset as usize, y + offset as usize),
+                        "{p0:?} -> {p1:?} at ({x}, {y})"
+                    );
+                }
+            }
+        }
+
+        // Far off-canvas endpoints only walk the visible part.
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(
+            Line::new((-1_000_000_000, 3), (1_000_000_000, 5)),
+            Style::outlined(),
+        );
+        assert!((0..16).all(|x| canvas.display().is_set(x, 4)));
+    }
+
+    #[test]
+    fn extreme_line_coordinates() {
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(
+            Line::new((i32::MIN / 2, 0), (i32::MAX / 2, 10)),
+            Style::outlined(),
+        );
+        canvas.draw(
+            Line::new((i32::MIN, i32::MIN), (i32::MAX, i32::MAX)),
+            Style::outlined().snap_axis(2.0),
+        );
+        canvas.draw(
+            Line::new((f64::MIN, 3.0), (f64::MAX, 3.0)),
+            Style::outlined().antialias(true),
+        );
+        assert!((0..16).all(|x| canvas.display().is_set(x, 3)));
+    }
+
+    #[test]
+    fn small_filled_circles() {
+        let draw = |radius| {
+            let mut canvas = Canvas::with_dot_size(6, 6);
+            canvas.draw(Circle::new((2, 2), radius), Style::filled());
+            (0..5)
+                .map(|y| {
+                    (0..5)
+                        .map(|x| {
+                            if canvas.display().is_set(x, y) {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(draw(1), [".....", "..#..", ".###.", "..#..", "....."]);
+        assert_eq!(draw(2), [".###.", "#####", "#####", "#####", ".###."]);
+
+        // Huge circles only visit the part on the canvas.
+        let mut huge = Canvas::with_dot_size(16, 16);
+        huge.draw(Circle::new((0, 0), i32::MAX), Style::filled());
+        assert!(huge.display().is_full());
+        let mut wide = Canvas::with_dot_size(16, 16);
+        wide.draw(Circle::new((-50_000, 8), 50_003), Style::filled());
+        assert!(wide.display().is_set(3, 8) && !wide.display().is_set(4, 8));
+    }
+
+    #[test]
+    fn thin_rects() {
+        let draw = |rect: Rect| {
+            let mut canvas = Canvas::with_dot_size(8, 4);
+            canvas.draw(rect, Style::outlined());
+            canvas.to_string()
+        };
+
+        assert_eq!(draw(Rect::new((1, 1), (6, 1))), "⠐⠒⠒⠂");
+        assert_eq!(draw(Rect::new((1, 0), (1, 4))), "⢸⠀⠀⠀");
+        assert_eq!(draw(Rect::new((7, 3), (-1, -3))), "⠀⠀⠀⠇");
+        assert_eq!(draw(Rect::new((2, 2), (1, 1))), "⠀⠄⠀⠀");
+    }
+
+    #[test]
+    fn rect_from_corners() {
+        let draw = |rect: Rect| {
+            let mut canvas = Canvas::with_dot_size(8, 8);
+            canvas.draw(rect, Style::outlined());
+            canvas.to_string()
+        };
+
+        let expected = draw(Rect::new((1, 2), (5, 4)));
+        for (a, b) in [
+            ((1, 2), (5, 5)),
+            ((5, 5), (1, 2)),
+            ((5, 2), (1, 5)),
+            ((1, 5), (5, 2)),
+        ] {
+            let rect = Rect::from_corners(a, b);
+            assert!(rect.contains(a) && rect.contains(b));
+            assert_eq!(draw(rect), expected, "{a:?} to {b:?}");
+        }
+
+        // Negative dimensions extend up and to the left of `top_left`.
+        assert_eq!(draw(Rect::new((6, 6), (-5, -4))), expected);
+        assert_eq!(
+            draw(Rect::from_corners((3, 3), (3, 3))),
+            draw(Rect::new((3, 3), (1, 1)))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        // Rects and circles agree with the dots a filled shape sets.
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        let rect = Rect::new((9, 12), (-5, -3));
+        canvas.draw(&rect, Style::filled());
+        let circle = Circle::new((8, 5), 4);
+        canvas.draw(&circle, Style::filled());
+        for y in -1..17 {
+            for x in -1..17 {
+                let expected = canvas.is_set((x, y));
+                assert_eq!(rect.contains((x, y)) || circle.contains((x, y)), expected);
+            }
+        }
+        assert!(!Circle::new((0, 0), -1).contains((0, 0)));
+
+        // Distances past the range of i32 don't overflow.
+        let huge = Circle::new((i32::MIN, 0), i32::MAX);
+        assert!(huge.contains((-1, 0)) && !huge.contains((0, 0)));
+        assert!(!huge.contains((i32::MAX, i32::MAX)));
+
+        let tri = Tri::new((0.0, 0.0), (10.0, 0.0), (0.0, 10.0));
+        assert!(tri.contains((2, 3)));
+        assert!(tri.contains((5.0, 5.0)));
+        assert!(!tri.contains((6, 6)));
+        assert!(!tri.contains((-1, 2)));
+        let flipped = Tri::new((0.0, 0.0), (0.0, 10.0), (10.0, 0.0));
+        assert!(flipped.contains((2, 3)));
+    }
+
+    #[test]
+    fn thick_circle() {
+        let mut canvas = Canvas::with_dot_size(24, 24);
+        canvas.draw(
+            Circle::new((12, 12), 8).with_thickness(3),
+            Style::outlined(),
+        );
+
+        // The band reaches one dot either side of the radius.
+        for (x, y) in [(12, 3), (12, 4), (12, 5), (19, 12), (20, 12), (21, 12)] {
+            assert!(canvas.is_set((x, y)), "({x}, {y})");
+        }
+        for (x, y) in [(12, 2), (12, 6), (12, 12), (18, 12), (22, 12)] {
+            assert!(!canvas.is_set((x, y)), "({x}, {y})");
+        }
+
+        // A thickness of 1 is the plain outline.
+        let mut thin = Canvas::with_dot_size(24, 24);
+        thin.draw(
+            Circle::new((12, 12), 8).with_thickness(0),
+            Style::outlined(),
+        );
+        let mut plain = Canvas::with_dot_size(24, 24);
+        plain.draw(Circle::new((12, 12), 8), Style::outlined());
+        assert_eq!(thin.display(), plain.display());
+    }
+
+    #[test]
+    fn sector() {
+        let quarter = || Sector::new((8, 8), 6, 0.0, core::f64::consts::FRAC_PI_2);
+
+        let mut filled = Canvas::with_dot_size(16, 16);
+        filled.draw(quarter(), Style::filled());
+        for (x, y) in [(8, 8), (11, 11), (14, 8), (8, 14), (12, 9)] {
+            assert!(filled.display().is_set(x, y), "({x}, {y})");
+        }
+        for (x, y) in [(5, 11), (11, 5), (7, 9), (15, 8)] {
+            assert!(!filled.display().is_set(x, y), "({x}, {y})");
+        }
+
+        // The outline is the two radii and the arc between them.
+        let mut outlined = Canvas::with_dot_size(16, 16);
+        outlined.draw(quarter(), Style::outlined());
+        for (x, y) in [(8, 8), (11, 8), (14, 8), (8, 11), (8, 14), (12, 12)] {
+            assert!(outlined.display().is_set(x, y), "({x}, {y})");
+        }
+        assert!(!outlined.display().is_set(10, 10));
+
+        // Each dot is in the slice if its angle from the center is.
+        for (start, end) in [(0.3, 2.0), (-1.0, 2.5), (2.0, 0.5), (1.0, 1.0)] {
+            let sector = Sector::new((8, 8), 6, start, end);
+            let mut canvas = Canvas::with_dot_size(16, 16);
+            canvas.draw(sector, Style::filled());
+
+            let sweep = Sector::new((8, 8), 6, start, end).sweep();
+            for y in -8..8 {
+                for x in -8..8 {
+                    let (xf, yf) = (x as f64, y as f64);
+                    let in_circle = xf * xf + yf * yf <= 6.5 * 6.5;
+                    let in_slice = (x, y) == (0, 0) || wrap_angle(yf.atan2(xf) - start) <= sweep;
+                    let set = canvas.display().is_set((x + 8) as usize, (y + 8) as usize);
+                    assert_eq!(set, in_circle && in_slice, "({start}, {end}): ({x}, {y})");
+                }
+            }
+        }
+
+        // Huge slices only visit the part on the canvas.
+        let mut huge = Canvas::with_dot_size(16, 16);
+        huge.draw(Sector::new((0, 0), i32::MAX, 0.0, 1.0), Style::filled());
+        assert!(huge.display().is_set(15, 0) && huge.display().is_set(15, 8));
+        assert!(!huge.display().is_set(0, 15));
+
+        // A full turn is a whole disc.
+        let mut disc = Canvas::with_dot_size(16, 16);
+        disc.draw(Sector::new((8, 8), 6, 1.0, 1.0 + TAU), Style::filled());
+        for (x, y) in [(2, 8), (8, 2), (14, 8), (8, 14), (4, 4)] {
+            assert!(disc.display().is_set(x, y), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn ring() {
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(Ring::new((8, 8), 3, 6), Style::filled());
+        for (x, y) in [(13, 8), (11, 8), (8, 14), (4, 4), (3, 8)] {
+            assert!(canvas.display().is_set(x, y), "({x}, {y})");
+        }
+        for (x, y) in [(8, 8), (10, 8), (8, 6), (15, 8)] {
+            assert!(!canvas.display().is_set(x, y), "({x}, {y})");
+        }
+
+        // The outline strokes both circles, leaving the band between empty.
+        let mut outlined = Canvas::with_dot_size(16, 16);
+        outlined.draw(Ring::new((8, 8), 3, 6), Style::outlined());
+        for (x, y) in [(14, 8), (11, 8), (8, 2), (8, 5)] {
+            assert!(outlined.display().is_set(x, y), "({x}, {y})");
+        }
+        assert!(!outlined.display().is_set(12, 8));
+
+        // With no hole, a ring is a circle.
+        let mut ring = Canvas::with_dot_size(16, 16);
+        ring.draw(Ring::new((8, 8), 0, 5), Style::filled().outline_on());
+        let mut circle = Canvas::with_dot_size(16, 16);
+        circle.draw(Circle::new((8, 8), 5), Style::filled().outline_on());
+        assert_eq!(ring.to_string(), circle.to_string());
+
+        // Huge rings only visit the part on the canvas.
+        let mut huge = Canvas::with_dot_size(16, 16);
+        huge.draw(Ring::new((0, 0), 5, i32::MAX), Style::filled());
+        assert!(huge.display().is_set(15, 15) && huge.display().is_set(5, 0));
+        assert!(!huge.display().is_set(0, 0) && !huge.display().is_set(3, 3));
+
+        // Radii past 46340 would overflow if squared as i32.
+        let mut wide = Canvas::with_dot_size(16, 16);
+        wide.draw(Ring::new((-50_000, 8), 10, 50_003), Style::filled());
+        assert!(wide.display().is_set(3, 8) && !wide.display().is_set(4, 8));
+    }
+
+    #[test]
+    fn spline() {
+        let points = [(1, 8), (5, 2), (10, 12), (14, 6)];
+
+        // The curve passes through every point.
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(Spline::new(points), Style::outlined());
+        for (x, y) in points {
+            assert!(canvas.display().is_set(x, y), "({x}, {y})");
+        }
+
+        // Full tension gives straight lines between the points.
+        let straight = Spline::new([(0, 0), (8, 4), (8, 12)])
+            .with_tension(1.0)
+            .flatten();
+        for &(x, y) in &straight {
+            let on_first = (y - x / 2.0).abs() < 1e-9 && x <= 8.0;
+            let on_second = (x - 8.0).abs() < 1e-9 && (4.0..=12.0).contains(&y);
+            assert!(on_first || on_second, "({x}, {y})");
+        }
+
+        // A single point is drawn as a dot.
+        let mut dot = Canvas::with_dot_size(4, 4);
+        dot.draw(Spline::new([(2, 1)]), Style::outlined());
+        assert!(dot.display().is_set(2, 1));
+    }
+
+    #[test]
+    fn thick_polyline_corner() {
+        let mut canvas = Canvas::with_dot_size(16, 16);
+        canvas.draw(
+            Polyline::new([(2, 2), (12, 2), (12, 12)]).with_width(3.0),
+            Style::outlined(),
+        );
+
+        // Every dot within half the width of the corner is covered,
+        // including the outside of the bend.
+        for (x, y) in [(13, 1), (11, 1), (13, 3), (11, 3), (12, 1), (13, 2)] {
+            assert!(canvas.display().is_set(x, y), "({x}, {y})");
+        }
+
+        // The stroke stays 3 dots wide along each segment.
+        let column: Vec<_> = (0..6).map(|y| canvas.display().is_set(6, y)).collect();
+        assert_eq!(column, vec![false, true, true, true, false, false]);
+
+        // A single point is a disc as wide as the stroke.
+        let mut point = Canvas::with_dot_size(8, 8);
+        point.draw(Polyline::new([(4, 4)]).with_width(3.0), Style::outlined());
+        for (x, y) in [(4, 4), (3, 4), (5, 4), (4, 3), (3, 3), (5, 5)] {
+            assert!(point.display().is_set(x, y), "({x}, {y})");
+        }
+        assert!(!point.display().is_set(2, 4) && !point.display().is_set(6, 4));
+    }
+
+    #[test]
+    fn antialiased_circle_partial_coverage() {
+        let (cx, cy, r): (i32, i32, i32) = (16, 16, 9);
+        let mut canvas = Canvas::with_dot_size(32, 32);
+        canvas.draw(Circle::new((cx, cy), r), Style::outlined().antialias(true));
+
+        let mut partial_set = 0;
+        let mut partial_unset = 0;
+        for y in 0..32 {
+            for x in 0..32 {
+                let distance = (((x - cx).pow(2) + (y - cy).pow(2)) as f64).sqrt();
+                let coverage = antialias_coverage(distance, r as f64);
+
+                if coverage > 0.0 && coverage < 1.0 {
+                    if canvas.display().is_set(x as usize, y as usize) {
+                        partial_set += 1;
+                    } else {
+                        partial_unset += 1;
+                    }
+                } else if coverage == 0.0 {
+                    assert!(!canvas.display().is_set(x as usize, y as usize));
+                }
+            }
+        }
+
+        // Partially covered dots are dithered rather than fully on or off.
+        assert!(partial_set > 0);
+        assert!(partial_unset > 0);
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+
+use super::{dither, Canvas, DitherMode};
+
+impl Canvas {
+    /// Draws a grayscale image with one dot per pixel, with its top-left
+    /// corner at the top-left of the canvas. `pixels` holds the rows of the
+    /// image one after another, `width` pixels each, where 0 is off and 255
+    /// is fully on. Any incomplete last row is ignored.
+    ///
+    /// `mode` picks between the canvas's ordered dithering and
+    /// Floyd–Steinberg error diffusion. Pixels falling outside the canvas
+    /// are cropped, but still take part in error diffusion.
+    pub fn draw_image_dithered(&mut self, pixels: &[u8], width: usize, mode: DitherMode) {
+        let values: Vec<_> = pixels.iter().map(|&p| p as f64 / 255.0).collect();
+        self.draw_values_dithered(&values, width, mode);
+    }
+
+    /// Creates a canvas from a grayscale image, with one dot per pixel. See
+    /// `from_image_with_size` for how pixels are mapped.
+    #[cfg(feature = "image")]
+    pub fn from_image(img: &::image::GrayImage, mode: DitherMode) -> Canvas {
+        let (w, h) = (img.width() as usize, img.height() as usize);
+        let mut canvas = Canvas::with_dot_size(w, h);
+        let values: Vec<_> = img.pixels().map(|p| srgb_to_linear(p.0[0])).collect();
+        canvas.draw_values_dithered(&values, w, mode);
+        canvas
+    }
+
+    /// Creates a canvas of the given dot size from a grayscale image,
+    /// scaling the image to fill the canvas. Each dot takes the average of
+    /// the pixels it covers.
+    ///
+    /// Pixel values are treated as sRGB and converted to linear light
+    /// before dithering, so the fraction of dots set in an area matches how
+    /// bright it looks. A mid-gray of 128 sets about 22% of its dots.
+    #[cfg(feature = "image")]
+    pub fn from_image_with_size(
+        img: &::image::GrayImage,
+        width: usize,
+        height: usize,
+        mode: DitherMode,
+    ) -> Canvas {
+        let mut canvas = Canvas::with_dot_size(width, height);
+        let (img_w, img_h) = (img.width() as usize, img.height() as usize);
+        if img_w == 0 || img_h == 0 {
+            return canvas;
+        }
+
+        // The range of source pixels covered by dot `i` out of `dots`.
+        let span = |i: usize, dots: usize, pixels: usize| {
+            let start = i * pixels / dots;
+            let end = ((i + 1) * pixels / dots).max(start + 1);
+            start..end.min(pixels)
+        };
+
+        let mut values = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (xs, ys) = (span(x, width, img_w), span(y, height, img_h));
+                let count = (xs.len() * ys.len()) as f64;
+                let sum: f64 = ys
+                    .flat_map(|py| xs.clone().map(move |px| (px, py)))
+                    .map(|(px, py)| srgb_to_linear(img.get_pixel(px as u32, py as u32).0[0]))
+                    .sum();
+                values.push(sum / count);
+            }
+        }
+
+        canvas.draw_values_dithered(&values, width, mode);
+        canvas
+    }
+
+    /// Like `draw_image_dithered`, but with values from `0.0` to `1.0`.
+    fn draw_values_dithered(&mut self, values: &[f64], width: usize, mode: DitherMode) {
+        if width == 0 {
+            return;
+        }
+        let height = values.len() / width;
+
+        match mode {
+            DitherMode::Ordered => {
+                for (i, &v) in values[..width * height].iter().enumerate() {
+                    self.set_with_level((i % width, i / width), v.clamp(0.0, 1.0));
+                }
+            }
+            DitherMode::FloydSteinberg => {
+                let mut values = values[..width * height].to_vec();
+
+                for y in 0..height {
+                    for x in 0..width {
+                        let old = values[y * width + x];
+                        let new = if old >= 0.5 { 1.0 } else { 0.0 };
+                        let b = new as usize * dither::max_brightness();
+                        self.set_with_brightness((x, y), b);
+
+                        let error = old - new;
+                        let mut diffuse = |dx: isize, dy: usize, weight: f64| {
+                            let nx = x as isize + dx;
+                            if (0..width as isize).contains(&nx) && y + dy < height {
+                                values[(y + dy) * width + nx as usize] += error * weight;
+                            }
+                        };
+
+                        diffuse(1, 0, 7.0 / 16.0);
+                        diffuse(-1, 1, 3.0 / 16.0);
+                        diffuse(0, 1, 5.0 / 16.0);
+                        diffuse(1, 1, 1.0 / 16.0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converts an sRGB-encoded 8-bit value to linear light from `0.0` to `1.0`.
+#[cfg(feature = "image")]
+fn srgb_to_linear(value: u8) -> f64 {
+    let v = value as f64 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coverage(canvas: &Canvas) -> f64 {
+        let (w, h) = canvas.dot_size();
+        let set = (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .filter(|&(x, y)| canvas.display().is_set(x, y))
+            .count();
+        set as f64 / (w * h) as f64
+    }
+
+    #[test]
+    fn half_gray_coverage() {
+        let gray = vec![128; 32 * 32];
+
+        let mut ordered = Canvas::with_dot_size(32, 32);
+        ordered.draw_image_dithered(&gray, 32, DitherMode::Ordered);
+
+        let mut diffused = Canvas::with_dot_size(32, 32);
+        diffused.draw_image_dithered(&gray, 32, DitherMode::FloydSteinberg);
+
+        let (ordered, diffused) = (coverage(&ordered), coverage(&diffused));
+        // 128 / 255 is just over half, so it lights one more threshold of
+        // each 8x8 tile than half does.
+        assert_eq!(ordered, 33.0 / 64.0);
+        assert!((diffused - ordered).abs() < 0.02, "{diffused} vs {ordered}");
+    }
+
+    #[test]
+    fn extremes() {
+        for mode in [DitherMode::Ordered, DitherMode::FloydSteinberg] {
+            let mut canvas = Canvas::with_dot_size(8, 8);
+            canvas.draw_image_dithered(&[255; 64], 8, mode);
+            assert_eq!(coverage(&canvas), 1.0);
+
+            canvas.draw_image_dithered(&[0; 64], 8, mode);
+            assert_eq!(coverage(&canvas), 0.0);
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn from_image() {
+        let img = ::image::GrayImage::from_pixel(15, 10, ::image::Luma([255]));
+        let canvas = Canvas::from_image(&img, DitherMode::Ordered);
+        assert_eq!(canvas.dot_size(), (15, 10));
+        assert!(canvas.display().is_set(14, 9));
+        assert!(!canvas.display().is_set(15, 9));
+        assert!(!canvas.display().is_set(14, 10));
+
+        // Mid-gray is darker than half coverage in linear light.
+        let img = ::image::GrayImage::from_pixel(64, 64, ::image::Luma([128]));
+        let canvas = Canvas::from_image_with_size(&img, 32, 32, DitherMode::Ordered);
+        assert!((coverage(&canvas) - 0.22).abs() < 0.02);
+    }
+}
@@ -1,14 +1,51 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+use alloc::{vec, vec::Vec};
+use core::{error::Error, fmt};
+
 use super::dither;
 
 /// Drawing style. A shape's `outline` and `fill` modes can be independently
 /// configured. Each field, if `Some`, indicates the dither brightness.
-#[derive(Default)]
+///
+/// Shapes that support it smooth their outline when `antialias` is set.
+/// Lines within `snap_axis` degrees of horizontal or vertical are drawn
+/// perfectly straight.
+///
+/// A `fill_pattern` replaces the dither threshold for fills, so that a
+/// shape's interior is drawn with the pattern's tile instead. A
+/// `fill_gradient` replaces the fill brightness, shading the interior
+/// across the shape's bounding box.
+///
+/// A `gamma`, if set, curves every brightness the style draws with so that
+/// evenly spaced inputs look evenly spaced on screen. See
+/// `Style::with_gamma`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Style {
     pub outline: Option<usize>,
     pub fill: Option<usize>,
+    pub antialias: bool,
+    pub snap_axis: Option<f64>,
+    pub fill_pattern: Option<Pattern>,
+    pub fill_gradient: Option<Gradient>,
+    pub gamma: Option<f64>,
 }
 
 impl Style {
+    /// The highest raw brightness value, at which every dot is set.
+    /// Brightness values range from `0` to this on every canvas, whatever
+    /// its dither matrix.
+    pub const MAX_BRIGHTNESS: usize = dither::max_brightness();
+
+    /// Converts a float brightness to the nearest raw brightness value. The
+    /// input is clamped to the range `0.0..=1.0`, which maps to
+    /// `0..=MAX_BRIGHTNESS`.
+    pub fn quantize_brightness(brightness: f64) -> usize {
+        let brightness = brightness.clamp(0.0, 1.0);
+        (Self::MAX_BRIGHTNESS as f64 * brightness).round() as usize
+    }
+
     /// Get a new `Style` object with nothing configured. Using this verbatim
     /// will result in nothing being drawn.
     pub fn none() -> Self {
@@ -30,6 +67,16 @@ impl Style {
         Self::none().fill_brightness_f64(brightness)
     }
 
+    /// Get a new `Style` object filled with a linear gradient from brightness
+    /// `from` to brightness `to`. See `Gradient`.
+    pub fn linear_gradient(from: usize, to: usize, angle_degrees: f64) -> Self {
+        Self::none().fill_on().gradient(Gradient {
+            from: from.min(dither::max_brightness()),
+            to: to.min(dither::max_brightness()),
+            angle_degrees,
+        })
+    }
+
     /// Get a new `Style` object with the outline set to full brightness.
     pub fn outlined() -> Self {
         Self::none().outline_on()
@@ -53,7 +100,8 @@ impl Style {
         }
     }
 
-    /// Sets the raw brightness value (dither threshold) for the fill (background).
+    /// Sets the raw brightness value (dither threshold) for the fill
+    /// (background). Values above `MAX_BRIGHTNESS` are clamped.
     pub fn fill_brightness(self, brightness: usize) -> Self {
         Self {
             fill: Some(brightness.min(dither::max_brightness())),
@@ -61,13 +109,20 @@ impl Style {
         }
     }
 
+    /// Like `fill_brightness`, but returns an error instead of clamping if
+    /// `brightness` is above `MAX_BRIGHTNESS`.
+    pub fn try_fill_brightness(self, brightness: usize) -> Result<Self, BrightnessError> {
+        Ok(Self {
+            fill: Some(BrightnessError::check(brightness)?),
+            ..self
+        })
+    }
+
     /// Sets the dither threshold for the fill based on a float.
     /// The input is clamped to the range `0.0..=1.0`.
     pub fn fill_brightness_f64(self, brightness: f64) -> Self {
-        let brightness = brightness.clamp(0.0, 1.0);
-        let b = dither::max_brightness() as f64 * brightness;
         Self {
-            fill: Some(b.round() as usize),
+            fill: Some(Self::quantize_brightness(brightness)),
             ..self
         }
     }
@@ -94,6 +149,7 @@ impl Style {
     }
 
     /// Sets the raw brightness value (dither threshold) for the outline.
+    /// Values above `MAX_BRIGHTNESS` are clamped.
     pub fn outline_brightness(self, brightness: usize) -> Self {
         Self {
             outline: Some(brightness.min(dither::max_brightness())),
@@ -101,13 +157,20 @@ impl Style {
         }
     }
 
+    /// Like `outline_brightness`, but returns an error instead of clamping
+    /// if `brightness` is above `MAX_BRIGHTNESS`.
+    pub fn try_outline_brightness(self, brightness: usize) -> Result<Self, BrightnessError> {
+        Ok(Self {
+            outline: Some(BrightnessError::check(brightness)?),
+            ..self
+        })
+    }
+
     /// Sets the dither threshold for the outline based on a float.
     /// The input is clamped to the range `0.0..=1.0`.
     pub fn outline_brightness_f64(self, brightness: f64) -> Self {
-        let brightness = brightness.clamp(0.0, 1.0);
-        let b = dither::max_brightness() as f64 * brightness;
         Self {
-            outline: Some(b.round() as usize),
+            outline: Some(Self::quantize_brightness(brightness)),
             ..self
         }
     }
@@ -128,15 +191,253 @@ impl Style {
         }
     }
 
+    /// Enables or disables anti-aliasing of the outline. Partially covered
+    /// dots are drawn with a proportionally lower dither brightness. Lines
+    /// and circles support it.
+    pub fn antialias(self, antialias: bool) -> Self {
+        Self { antialias, ..self }
+    }
+
+    /// Draws lines that are within `tolerance_degrees` of horizontal or
+    /// vertical as perfectly straight lines, which avoids stray steps in
+    /// near-flat lines (e.g. when plotting jittery data).
+    pub fn snap_axis(self, tolerance_degrees: f64) -> Self {
+        Self {
+            snap_axis: Some(tolerance_degrees),
+            ..self
+        }
+    }
+
+    /// Fills with `pattern` instead of dithering. Dots where the tile is set
+    /// are drawn and the rest are cleared, unless the fill brightness is 0,
+    /// in which case every dot is cleared as usual.
+    pub fn fill_pattern(self, pattern: Pattern) -> Self {
+        Self {
+            fill_pattern: Some(pattern),
+            ..self
+        }
+    }
+
+    /// Sets the gamma the canvas curves the style's brightnesses with: the
+    /// outline, fill, and gradient are each raised to this power, as a
+    /// fraction of `MAX_BRIGHTNESS`, when they are dithered. Dot density
+    /// doesn't look linear, so a gamma around `2.2` makes `0.5` look about
+    /// half as bright as `1.0`. The brightnesses themselves are stored
+    /// unchanged, so this can be called before or after setting them. A
+    /// gamma that isn't a positive number resets the style to the default
+    /// linear mapping.
+    pub fn with_gamma(self, gamma: f64) -> Self {
+        Self {
+            gamma: Some(gamma).filter(|g| g.is_finite() && *g > 0.0),
+            ..self
+        }
+    }
+
+    /// Fills with `gradient` instead of a single brightness. The fill must
+    /// also be set for anything to be filled.
+    pub fn gradient(self, gradient: Gradient) -> Self {
+        Self {
+            fill_gradient: Some(gradient),
+            ..self
+        }
+    }
+
+    /// Returns the style with its brightness values inverted, so that dots
+    /// it would set are cleared and vice versa. Each outline, fill, and
+    /// gradient brightness `b` becomes `MAX_BRIGHTNESS - b`, while unset
+    /// fields stay unset. This draws a shape as a "hole" in whatever is
+    /// already on the canvas.
+    pub fn inverted(self) -> Self {
+        let invert = |b: usize| Self::MAX_BRIGHTNESS - b.min(Self::MAX_BRIGHTNESS);
+        Self {
+            outline: self.outline.map(invert),
+            fill: self.fill.map(invert),
+            fill_gradient: self.fill_gradient.map(|g| Gradient {
+                from: invert(g.from),
+                to: invert(g.to),
+                ..g
+            }),
+            ..self
+        }
+    }
+
     /// Return the "distinguishable outline" for the style. If the fill
     /// and the outline are both set to the same brightness, there is no
     /// difference between how they are rendered and it is a waste to
-    /// draw the outline.
+    /// draw the outline. A patterned or gradient fill is always
+    /// distinguishable.
     pub(super) fn distinguishable_outline(&self) -> Option<usize> {
-        self.outline.filter(|&o| self.fill.is_none_or(|f| f != o))
+        let uniform_fill = self.fill_pattern.is_none() && self.fill_gradient.is_none();
+        self.outline
+            .filter(|&o| !uniform_fill || self.fill.is_none_or(|f| f != o))
+    }
+}
+
+/// A raw brightness value was above `Style::MAX_BRIGHTNESS`. Holds the
+/// rejected value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrightnessError(pub usize);
+
+impl BrightnessError {
+    /// Passes `brightness` through if it is in range.
+    fn check(brightness: usize) -> Result<usize, Self> {
+        match brightness {
+            0..=Style::MAX_BRIGHTNESS => Ok(brightness),
+            _ => Err(Self(brightness)),
+        }
+    }
+}
+
+impl fmt::Display for BrightnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "brightness {} is above the maximum of {}",
+            self.0,
+            Style::MAX_BRIGHTNESS
+        )
+    }
+}
+
+impl Error for BrightnessError {}
+
+/// A linear brightness gradient for fills. See `Style::linear_gradient`.
+///
+/// The brightness runs from `from` to `to` across the bounding box of the
+/// shape being filled, in the direction given by `angle_degrees`: 0 runs
+/// left to right and 90 runs top to bottom. Each dot is then dithered as
+/// usual.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient {
+    pub from: usize,
+    pub to: usize,
+    pub angle_degrees: f64,
+}
+
+impl Gradient {
+    /// Get the brightness at (x, y) for a shape spanning `min..=max`, as a
+    /// fraction of `Style::MAX_BRIGHTNESS`. It is only rounded to the
+    /// finest steps any matrix can tell apart, so that the canvas can
+    /// dither it at the full precision of its matrix.
+    pub(super) fn level_at(&self, (x, y): (f64, f64), min: (f64, f64), max: (f64, f64)) -> f64 {
+        let (sin, cos) = self.angle_degrees.to_radians().sin_cos();
+        let project = |(x, y): (f64, f64)| x * cos + y * sin;
+
+        let corners = [min, (max.0, min.1), (min.0, max.1), max].map(project);
+        let start = corners.iter().copied().fold(f64::INFINITY, f64::min);
+        let end = corners.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let t = if end - start > 0.0 {
+            ((project((x, y)) - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        // Rounding also hides the error from `sin_cos`, which would
+        // otherwise light the lowest threshold where the gradient is 0.
+        let (from, to) = (self.from as f64, self.to as f64);
+        let steps = (dither::MAX_THRESHOLD + 1) as f64;
+        let level = (from + (to - from) * t) / Style::MAX_BRIGHTNESS as f64;
+        (level * steps).round() / steps
+    }
+}
+
+/// A small tile of on/off dots, repeated across the canvas to fill shapes.
+/// See `Style::fill_pattern`. Tiles can be up to 8x8 dots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pattern {
+    width: usize,
+    height: usize,
+    /// Row-major bits of the tile, `width` bits per row.
+    bits: u64,
+}
+
+impl Pattern {
+    /// The largest width or height of a tile.
+    pub const MAX_SIZE: usize = 8;
+
+    /// Validates and wraps a tile given as rows of dots.
+    pub fn new(rows: Vec<Vec<bool>>) -> Result<Self, PatternError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(PatternError::Empty);
+        }
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(PatternError::NotRectangular);
+        }
+        if width > Self::MAX_SIZE || rows.len() > Self::MAX_SIZE {
+            return Err(PatternError::TooLarge);
+        }
+
+        let bits = rows
+            .iter()
+            .flatten()
+            .enumerate()
+            .fold(0, |bits, (i, &dot)| bits | (dot as u64) << i);
+
+        Ok(Self {
+            width,
+            height: rows.len(),
+            bits,
+        })
+    }
+
+    /// Every other dot, alternating on each row.
+    pub fn checkerboard() -> Self {
+        Self::new(vec![vec![true, false], vec![false, true]]).unwrap()
+    }
+
+    /// Every other row of dots.
+    pub fn horizontal_lines() -> Self {
+        Self::new(vec![vec![true], vec![false]]).unwrap()
+    }
+
+    /// Every other column of dots.
+    pub fn vertical_lines() -> Self {
+        Self::new(vec![vec![true, false]]).unwrap()
+    }
+
+    /// Diagonal lines running from the bottom-left to the top-right.
+    pub fn diagonal_hatch() -> Self {
+        Self::new(
+            (0..4)
+                .map(|y| (0..4).map(|x| (x + y) % 4 == 3).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    /// Get whether the dot at (x, y) is set, tiling the pattern across the
+    /// canvas.
+    pub fn is_set(&self, x: usize, y: usize) -> bool {
+        let i = (y % self.height) * self.width + x % self.width;
+        self.bits & (1 << i) != 0
+    }
+}
+
+/// The reasons a fill pattern can be rejected by `Pattern::new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternError {
+    /// The tile has no rows, or its first row is empty.
+    Empty,
+    /// The rows of the tile are not all the same length.
+    NotRectangular,
+    /// The tile is wider or taller than `Pattern::MAX_SIZE`.
+    TooLarge,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "pattern is empty"),
+            Self::NotRectangular => write!(f, "pattern rows differ in length"),
+            Self::TooLarge => write!(f, "pattern is larger than {0}x{0} dots", Pattern::MAX_SIZE),
+        }
     }
 }
 
+impl Error for PatternError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,25 +447,141 @@ mod tests {
         let no_distinguishable_outline = Style {
             outline: Some(14),
             fill: Some(14),
+            ..Style::none()
         };
         assert_eq!(no_distinguishable_outline.distinguishable_outline(), None);
 
         let no_outline = Style {
             outline: None,
             fill: Some(16),
+            ..Style::none()
         };
         assert_eq!(no_outline.distinguishable_outline(), None);
 
         let no_fill = Style {
             outline: Some(5),
             fill: None,
+            ..Style::none()
         };
         assert_eq!(no_fill.distinguishable_outline(), Some(5));
 
         let distinguishable_outline = Style {
             outline: Some(16),
             fill: Some(5),
+            ..Style::none()
         };
         assert_eq!(distinguishable_outline.distinguishable_outline(), Some(16));
     }
+
+    #[test]
+    fn quantize_brightness() {
+        assert_eq!(Style::quantize_brightness(0.0), 0);
+        assert_eq!(Style::quantize_brightness(0.5), Style::MAX_BRIGHTNESS / 2);
+        assert_eq!(Style::quantize_brightness(1.0), Style::MAX_BRIGHTNESS);
+        assert_eq!(Style::quantize_brightness(-1.0), 0);
+        assert_eq!(Style::quantize_brightness(2.0), Style::MAX_BRIGHTNESS);
+        assert_eq!(
+            Style::filled_with_brightness(usize::MAX).fill,
+            Some(Style::MAX_BRIGHTNESS)
+        );
+    }
+
+    #[test]
+    fn try_brightness() {
+        let max = Style::MAX_BRIGHTNESS;
+        let style = Style::none().try_outline_brightness(max).unwrap();
+        assert_eq!(style.outline, Some(max));
+        let style = Style::none().try_fill_brightness(3).unwrap();
+        assert_eq!(style.fill, Some(3));
+
+        assert_eq!(
+            Style::none()
+                .try_outline_brightness(max + 1)
+                .map(|s| s.outline),
+            Err(BrightnessError(max + 1))
+        );
+        assert_eq!(
+            Style::none()
+                .try_fill_brightness(usize::MAX)
+                .map(|s| s.fill),
+            Err(BrightnessError(usize::MAX))
+        );
+    }
+
+    #[test]
+    fn inverted() {
+        let style = Style::outlined().fill_brightness(16).inverted();
+        assert_eq!(style.outline, Some(0));
+        assert_eq!(style.fill, Some(Style::MAX_BRIGHTNESS - 16));
+        assert_eq!(Style::filled().inverted().outline, None);
+
+        let gradient = Style::linear_gradient(0, 48, 90.0).inverted();
+        let g = gradient.fill_gradient.unwrap();
+        assert_eq!((g.from, g.to), (Style::MAX_BRIGHTNESS, 16));
+        assert_eq!(g.angle_degrees, 90.0);
+    }
+
+    #[test]
+    fn gamma() {
+        // The brightness is stored linearly whichever is set first.
+        let before = Style::none().with_gamma(2.0).fill_brightness_f64(0.5);
+        let after = Style::filled_with_brightness_f64(0.5).with_gamma(2.0);
+        for style in [before, after] {
+            assert_eq!(style.fill, Some(Style::MAX_BRIGHTNESS / 2));
+            assert_eq!(style.gamma, Some(2.0));
+        }
+
+        // Invalid gammas fall back to linear.
+        let curved = Style::none().with_gamma(2.0);
+        assert_eq!(curved.with_gamma(0.0).gamma, None);
+        assert_eq!(curved.with_gamma(f64::NAN).gamma, None);
+    }
+
+    #[test]
+    fn gradient() {
+        let horizontal = Gradient {
+            from: 0,
+            to: 64,
+            angle_degrees: 0.0,
+        };
+        let (min, max) = ((0.0, 0.0), (8.0, 4.0));
+        assert_eq!(horizontal.level_at((0.0, 2.0), min, max), 0.0);
+        assert_eq!(horizontal.level_at((4.0, 0.0), min, max), 0.5);
+        assert_eq!(horizontal.level_at((8.0, 4.0), min, max), 1.0);
+        assert_eq!(horizontal.level_at((1.0, 0.0), min, max), 0.125);
+
+        let vertical = Gradient {
+            angle_degrees: 90.0,
+            ..horizontal
+        };
+        assert_eq!(vertical.level_at((8.0, 0.0), min, max), 0.0);
+        assert_eq!(vertical.level_at((0.0, 4.0), min, max), 1.0);
+    }
+
+    #[test]
+    fn pattern() {
+        let checkerboard = Pattern::checkerboard();
+        assert!(checkerboard.is_set(0, 0));
+        assert!(!checkerboard.is_set(1, 0));
+        assert!(checkerboard.is_set(3, 5));
+
+        let hatch = Pattern::diagonal_hatch();
+        assert!(hatch.is_set(3, 0));
+        assert!(hatch.is_set(2, 5));
+        assert!(!hatch.is_set(0, 0));
+
+        assert_eq!(Pattern::new(vec![]), Err(PatternError::Empty));
+        assert_eq!(
+            Pattern::new(vec![vec![true], vec![true, false]]),
+            Err(PatternError::NotRectangular)
+        );
+        assert_eq!(
+            Pattern::new(vec![vec![true; 9]]),
+            Err(PatternError::TooLarge)
+        );
+        assert_eq!(
+            PatternError::TooLarge.to_string(),
+            "pattern is larger than 8x8 dots"
+        );
+    }
 }
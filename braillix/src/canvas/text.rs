@@ -0,0 +1,341 @@
+use super::{coords::ToCoords, Canvas, Style};
+
+const GLYPH_WIDTH: usize = 3;
+const GLYPH_HEIGHT: usize = 5;
+const LINE_SPACING: usize = 1;
+
+/// Each glyph is five rows of three bits, top to bottom. Within a row,
+/// `0b100` is the left column and `0b001` is the right column.
+type Glyph = [u8; GLYPH_HEIGHT];
+
+/// Glyphs for `' '..='`'`. Lowercase letters reuse the uppercase glyphs.
+#[rustfmt::skip]
+const FONT: [Glyph; 65] = [
+    [0, 0, 0, 0, 0], // ' '
+    [2, 2, 2, 0, 2], // !
+    [5, 5, 0, 0, 0], // "
+    [5, 7, 5, 7, 5], // #
+    [3, 6, 7, 3, 6], // $
+    [5, 1, 2, 4, 5], // %
+    [2, 5, 2, 5, 3], // &
+    [2, 2, 0, 0, 0], // '
+    [1, 2, 2, 2, 1], // (
+    [4, 2, 2, 2, 4], // )
+    [0, 5, 2, 5, 0], // *
+    [0, 2, 7, 2, 0], // +
+    [0, 0, 0, 2, 4], // ,
+    [0, 0, 7, 0, 0], // -
+    [0, 0, 0, 0, 2], // .
+    [1, 1, 2, 4, 4], // /
+    [7, 5, 5, 5, 7], // 0
+    [2, 6, 2, 2, 7], // 1
+    [7, 1, 7, 4, 7], // 2
+    [7, 1, 3, 1, 7], // 3
+    [5, 5, 7, 1, 1], // 4
+    [7, 4, 7, 1, 7], // 5
+    [7, 4, 7, 5, 7], // 6
+    [7, 1, 1, 1, 1], // 7
+    [7, 5, 7, 5, 7], // 8
+    [7, 5, 7, 1, 7], // 9
+    [0, 2, 0, 2, 0], // :
+    [0, 2, 0, 2, 4], // ;
+    [1, 2, 4, 2, 1], // <
+    [0, 7, 0, 7, 0], // =
+    [4, 2, 1, 2, 4], // >
+    [7, 1, 3, 0, 2], // ?
+    [2, 5, 7, 4, 3], // @
+    [2, 5, 7, 5, 5], // A
+    [6, 5, 6, 5, 6], // B
+    [3, 4, 4, 4, 3], // C
+    [6, 5, 5, 5, 6], // D
+    [7, 4, 6, 4, 7], // E
+    [7, 4, 6, 4, 4], // F
+    [3, 4, 5, 5, 3], // G
+    [5, 5, 7, 5, 5], // H
+    [7, 2, 2, 2, 7], // I
+    [1, 1, 1, 5, 2], // J
+    [5, 5, 6, 5, 5], // K
+    [4, 4, 4, 4, 7], // L
+    [5, 7, 7, 5, 5], // M
+    [6, 5, 5, 5, 5], // N
+    [2, 5, 5, 5, 2], // O
+    [6, 5, 6, 4, 4], // P
+    [2, 5, 5, 6, 3], // Q
+    [6, 5, 6, 5, 5], // R
+    [3, 4, 2, 1, 6], // S
+    [7, 2, 2, 2, 2], // T
+    [5, 5, 5, 5, 7], // U
+    [5, 5, 5, 5, 2], // V
+    [5, 5, 7, 7, 5], // W
+    [5, 5, 2, 5, 5], // X
+    [5, 5, 2, 2, 2], // Y
+    [7, 1, 2, 4, 7], // Z
+    [6, 4, 4, 4, 6], // [
+    [4, 4, 2, 1, 1], // \
+    [3, 1, 1, 1, 3], // ]
+    [2, 5, 0, 0, 0], // ^
+    [0, 0, 0, 0, 7], // _
+    [4, 2, 0, 0, 0], // `
+];
+
+/// Glyphs for `'{'..='~'`.
+#[rustfmt::skip]
+const FONT_BRACES: [Glyph; 4] = [
+    [3, 2, 4, 2, 3], // {
+    [2, 2, 2, 2, 2], // |
+    [6, 2, 1, 2, 6], // }
+    [0, 3, 6, 0, 0], // ~
+];
+
+/// Drawn in place of characters the font doesn't cover.
+const MISSING_GLYPH: Glyph = [7, 7, 7, 7, 7];
+
+fn glyph(c: char) -> Glyph {
+    match c.to_ascii_uppercase() {
+        c @ ' '..='`' => FONT[c as usize - ' ' as usize],
+        c @ '{'..='~' => FONT_BRACES[c as usize - '{' as usize],
+        _ => MISSING_GLYPH,
+    }
+}
+
+/// Measures the size (width, height) in dots that `text` takes up when
+/// drawn with the given spacing between characters.
+fn text_size(text: &str, spacing: usize) -> (usize, usize) {
+    let line_width = |line: &str| match line.chars().count() {
+        0 => 0,
+        n => n * GLYPH_WIDTH + (n - 1) * spacing,
+    };
+
+    let width = text.lines().map(line_width).max().unwrap_or(0);
+    let height = match text.lines().count() {
+        0 => 0,
+        n => n * GLYPH_HEIGHT + (n - 1) * LINE_SPACING,
+    };
+
+    (width, height)
+}
+
+/// Where text drawn with `Canvas::draw_text_aligned` sits relative to its
+/// anchor point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// The anchor is the left edge of the text.
+    #[default]
+    Left,
+    /// The anchor is the middle of the text.
+    Center,
+    /// The anchor is the right edge of the text.
+    Right,
+}
+
+impl Align {
+    /// Gets how far from the left edge of something `width` dots wide its
+    /// anchor is.
+    fn offset(self, width: usize) -> i32 {
+        match self {
+            Align::Left => 0,
+            Align::Center => (width / 2) as i32,
+            Align::Right => width.saturating_sub(1) as i32,
+        }
+    }
+}
+
+impl Canvas {
+    /// Draws `text` using the built-in 3x5 font, with the top-left corner
+    /// of the first character at `origin` and one dot between characters.
+    /// See `draw_text_spaced` for details.
+    pub fn draw_text(&mut self, origin: impl ToCoords, text: &str, style: Style) -> usize {
+        self.draw_text_spaced(origin, text, 1, style)
+    }
+
+    /// Draws `text` using the built-in 3x5 font, with the top-left corner
+    /// of the first character at `origin` and `spacing` dots between
+    /// characters. Each `'\n'` starts a new line below the previous one.
+    ///
+    /// The font covers printable ASCII, with lowercase letters drawn as
+    /// uppercase. Any other character is drawn as a solid block.
+    ///
+    /// Glyph dots are drawn with the outline brightness. If the style has a
+    /// fill, the area behind the text is filled first, which keeps labels
+    /// legible on top of other drawing.
+    ///
+    /// Returns the width of the drawn text in dots.
+    pub fn draw_text_spaced(
+        &mut self,
+        origin: impl ToCoords,
+        text: &str,
+        spacing: usize,
+        style: Style,
+    ) -> usize {
+        let (ox, oy) = origin.to_coords_i32();
+        let (width, height) = text_size(text, spacing);
+
+        // Text with only empty lines has height but no width, and nothing
+        // behind it to fill.
+        if let Some(brightness) = style.fill.filter(|_| width > 0 && height > 0) {
+            let area = (ox, oy, width as i32, height as i32);
+            self.fill_with(&style, area, |canvas| {
+                for y in 0..height as i32 {
+                    canvas.draw_hor_line((ox, oy + y), (ox + width as i32 - 1, oy + y), brightness);
+                }
+            });
+        }
+
+        if let Some(brightness) = style.outline {
+            let advance = (GLYPH_WIDTH + spacing) as i32;
+            let line_advance = (GLYPH_HEIGHT + LINE_SPACING) as i32;
+
+            for (row, line) in text.lines().enumerate() {
+                let top = oy + row as i32 * line_advance;
+
+                for (col, c) in line.chars().enumerate() {
+                    let left = ox + col as i32 * advance;
+
+                    for (gy, bits) in glyph(c).into_iter().enumerate() {
+                        for gx in 0..GLYPH_WIDTH {
+                            if bits & (0b100 >> gx) != 0 {
+                                let p = (left + gx as i32, top + gy as i32);
+                                self.set_with_brightness(p, brightness);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        width
+    }
+
+    /// Draws `text` like `draw_text`, but positioned around `anchor` as
+    /// given by `align`: the top of the text is at the anchor's y, and its
+    /// left edge, middle, or right edge is at the anchor's x. Each line of
+    /// multi-line text is aligned the same way.
+    ///
+    /// Returns the width of the drawn text in dots.
+    pub fn draw_text_aligned(
+        &mut self,
+        anchor: impl ToCoords,
+        text: &str,
+        align: Align,
+        style: Style,
+    ) -> usize {
+        let (ax, ay) = anchor.to_coords_i32();
+        let (width, _) = text_size(text, 1);
+        let left = ax - align.offset(width);
+
+        // Fill the background behind the whole block, then draw each line.
+        self.draw_text(
+            (left, ay),
+            text,
+            Style {
+                outline: None,
+                ..style
+            },
+        );
+
+        let line_advance = (GLYPH_HEIGHT + LINE_SPACING) as i32;
+        for (row, line) in text.lines().enumerate() {
+            let (line_width, _) = text_size(line, 1);
+            let x = ax - align.offset(line_width);
+            let y = ay + row as i32 * line_advance;
+            self.draw_text(
+                (x, y),
+                line,
+                Style {
+                    fill: None,
+                    ..style
+                },
+            );
+        }
+
+        width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(canvas: &Canvas, width: usize, height: usize) -> Vec<String> {
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| match canvas.display().is_set(x, y) {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn draw_text() {
+        let mut canvas = Canvas::with_dot_size(12, 8);
+        let width = canvas.draw_text((1, 1), "hi", Style::outlined());
+        assert_eq!(width, 7);
+        assert_eq!(
+            rows(&canvas, 9, 7),
+            vec![
+                ".........",
+                ".#.#.###.",
+                ".#.#..#..",
+                ".###..#..",
+                ".#.#..#..",
+                ".#.#.###.",
+                ".........",
+            ]
+        );
+
+        // Empty lines have no background to fill.
+        let mut canvas = Canvas::with_dot_size(12, 8);
+        assert_eq!(canvas.draw_text((0, 0), "\n", Style::filled()), 0);
+        assert_eq!(canvas.draw_text((4, 0), "", Style::filled()), 0);
+        assert!(canvas.display().is_empty());
+    }
+
+    #[test]
+    fn spacing_and_missing_glyphs() {
+        let mut canvas = Canvas::with_dot_size(12, 8);
+        let width = canvas.draw_text_spaced((0, 0), "-é", 3, Style::outlined());
+        assert_eq!(width, 9);
+        assert_eq!(
+            rows(&canvas, 10, 5),
+            vec![
+                "......###.",
+                "......###.",
+                "###...###.",
+                "......###.",
+                "......###.",
+            ]
+        );
+    }
+
+    #[test]
+    fn draw_text_aligned() {
+        let draw = |align| {
+            let mut canvas = Canvas::with_dot_size(12, 12);
+            canvas.draw_text_aligned((5, 0), "i\n-", align, Style::outlined());
+            rows(&canvas, 11, 9)
+        };
+
+        assert_eq!(draw(Align::Left)[0], ".....###...");
+        assert_eq!(draw(Align::Center)[0], "....###....");
+        assert_eq!(draw(Align::Right)[0], "...###.....");
+        assert_eq!(draw(Align::Right)[8], "...###.....");
+
+        // Left alignment is plain `draw_text`.
+        let mut aligned = Canvas::with_dot_size(16, 16);
+        aligned.draw_text_aligned((1, 2), "ab\nc", Align::Left, Style::outlined().fill_off());
+        let mut plain = Canvas::with_dot_size(16, 16);
+        plain.draw_text((1, 2), "ab\nc", Style::outlined().fill_off());
+        assert_eq!(aligned.to_string(), plain.to_string());
+    }
+
+    #[test]
+    fn text_size() {
+        assert_eq!(super::text_size("", 1), (0, 0));
+        assert_eq!(super::text_size("abc\nde", 1), (11, 11));
+        assert_eq!(super::text_size("abc\nde", 0), (9, 11));
+    }
+}
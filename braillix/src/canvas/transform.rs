@@ -0,0 +1,163 @@
+use core::ops::Mul;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+use super::{coords::ToCoords, Rasterize};
+
+/// A 2D affine transform, stored as the top two rows of a 3x3 matrix:
+///
+/// ```text
+/// | a b c |
+/// | d e f |
+/// | 0 0 1 |
+/// ```
+///
+/// Points are transformed as column vectors, so `(x, y)` maps to
+/// `(a*x + b*y + c, d*x + e*y + f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2 {
+    m: [[f64; 3]; 2],
+}
+
+impl Affine2 {
+    /// The transform that leaves every point where it is.
+    pub const IDENTITY: Self = Self {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    };
+
+    /// Creates a transform from the top two rows of its matrix.
+    pub fn from_matrix(m: [[f64; 3]; 2]) -> Self {
+        Self { m }
+    }
+
+    /// Gets the top two rows of the transform's matrix.
+    pub fn matrix(&self) -> [[f64; 3]; 2] {
+        self.m
+    }
+
+    /// Creates a transform that moves points by `(dx, dy)`.
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self::from_matrix([[1.0, 0.0, dx], [0.0, 1.0, dy]])
+    }
+
+    /// Creates a transform that rotates points around the origin by `theta`
+    /// radians. Since the y-axis points down, positive angles rotate
+    /// clockwise on screen.
+    pub fn rotate(theta: f64) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::from_matrix([[cos, -sin, 0.0], [sin, cos, 0.0]])
+    }
+
+    /// Creates a transform that scales points away from the origin.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::from_matrix([[sx, 0.0, 0.0], [0.0, sy, 0.0]])
+    }
+
+    /// Creates a transform that shears points: `x` is offset by `kx * y`
+    /// and `y` is offset by `ky * x`.
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Self::from_matrix([[1.0, kx, 0.0], [ky, 1.0, 0.0]])
+    }
+
+    /// Returns a transform that applies `self` followed by `next`.
+    pub fn then(self, next: Self) -> Self {
+        next * self
+    }
+
+    /// Maps a point through the transform.
+    pub fn apply(&self, p: impl ToCoords) -> (f64, f64) {
+        let (x, y) = p.to_coords_f64();
+        let [[a, b, c], [d, e, f]] = self.m;
+        (a * x + b * y + c, d * x + e * y + f)
+    }
+
+    /// The factor by which the transform scales areas, as a length.
+    /// Used to approximate how a radius changes under the transform.
+    pub(super) fn length_scale(&self) -> f64 {
+        let [[a, b, _], [d, e, _]] = self.m;
+        (a * e - b * d).abs().sqrt()
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Composes two transforms as matrices: `(lhs * rhs)` applies `rhs` first.
+impl Mul for Affine2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let [[a0, b0, c0], [d0, e0, f0]] = self.m;
+        let [[a1, b1, c1], [d1, e1, f1]] = rhs.m;
+        Self::from_matrix([
+            [a0 * a1 + b0 * d1, a0 * b1 + b0 * e1, a0 * c1 + b0 * f1 + c0],
+            [d0 * a1 + e0 * d1, d0 * b1 + e0 * e1, d0 * c1 + e0 * f1 + f0],
+        ])
+    }
+}
+
+/// Shapes implementing `Transform` can be mapped through an `Affine2`
+/// before being drawn with `Canvas::draw_transformed`.
+///
+/// A shape's defining points are transformed, which may produce a different
+/// kind of shape (e.g. a rotated `Rect` becomes a `Quad`).
+pub trait Transform {
+    /// The shape produced by transforming `self`.
+    type Output: Rasterize;
+
+    /// Maps the shape's defining points through `affine`.
+    fn transform(&self, affine: &Affine2) -> Self::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::canvas::{geometry::Rect, Canvas, Style};
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn composition_order() {
+        let t = Affine2::scale(2.0, 3.0)
+            .then(Affine2::rotate(FRAC_PI_2))
+            .then(Affine2::translate(10.0, 0.0));
+
+        // (1, 1) -> (2, 3) -> (-3, 2) -> (7, 2)
+        assert_close(t.apply((1.0, 1.0)), (7.0, 2.0));
+        assert_close(Affine2::shear(1.0, 0.0).apply((1, 2)), (3.0, 2.0));
+        assert_close(Affine2::IDENTITY.apply((4, 5)), (4.0, 5.0));
+    }
+
+    #[test]
+    fn identity_rect_matches_rect() {
+        let mut plain = Canvas::with_dot_size(12, 12);
+        let mut transformed = Canvas::with_dot_size(12, 12);
+
+        plain.draw(Rect::new((2, 3), (7, 5)), Style::outlined());
+        transformed.draw_transformed(
+            Rect::new((2, 3), (7, 5)),
+            Style::outlined(),
+            Affine2::IDENTITY,
+        );
+        assert_eq!(plain.display(), transformed.display());
+
+        plain.draw(Rect::new((2, 3), (7, 5)), Style::filled());
+        transformed.draw_transformed(
+            Rect::new((2, 3), (7, 5)),
+            Style::filled(),
+            Affine2::IDENTITY,
+        );
+        assert_eq!(plain.display(), transformed.display());
+    }
+}
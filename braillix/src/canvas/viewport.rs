@@ -0,0 +1,160 @@
+use super::{coords::ToCoords, Affine2, Canvas, Style, Transform};
+
+/// Maps a rectangle of world coordinates onto the dots of a canvas, so
+/// drawings can be made in whatever units suit the data.
+///
+/// `world_rect` is `(x, y, width, height)` in world units, and its edges
+/// land on the outermost dots of a canvas `dot_size` dots in size. Screen
+/// y grows downward; set `flip_y` to have world y grow upward instead, as
+/// in most plots. The world rectangle must have a nonzero width and height.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub world_rect: (f64, f64, f64, f64),
+    pub dot_size: (usize, usize),
+    pub flip_y: bool,
+}
+
+impl Viewport {
+    /// Creates a viewport showing `world_rect` on a canvas of `dot_size`,
+    /// with y growing downward.
+    pub fn new(world_rect: (f64, f64, f64, f64), dot_size: (usize, usize)) -> Self {
+        Self {
+            world_rect,
+            dot_size,
+            flip_y: false,
+        }
+    }
+
+    /// Sets whether world y grows upward.
+    pub fn flip_y(self, flip_y: bool) -> Self {
+        Self { flip_y, ..self }
+    }
+
+    /// Gets the transform from world coordinates to dot coordinates.
+    pub fn transform(&self) -> Affine2 {
+        let (x, y, w, h) = self.world_rect;
+        let (dw, dh) = self.dot_size;
+        let sx = dw.saturating_sub(1) as f64 / w;
+        let sy = dh.saturating_sub(1) as f64 / h;
+
+        if self.flip_y {
+            let bottom = dh.saturating_sub(1) as f64;
+            Affine2::from_matrix([[sx, 0.0, -x * sx], [0.0, -sy, bottom + y * sy]])
+        } else {
+            Affine2::from_matrix([[sx, 0.0, -x * sx], [0.0, sy, -y * sy]])
+        }
+    }
+
+    /// Maps a point in world coordinates to the nearest dot.
+    pub fn world_to_dot(&self, p: impl ToCoords) -> (i32, i32) {
+        self.transform().apply(p).to_coords_i32()
+    }
+}
+
+/// A canvas borrowed through a `Viewport`, which interprets the coordinates
+/// of everything drawn on it in world space. Created with
+/// `Canvas::with_viewport`.
+pub struct ViewportCanvas<'a> {
+    canvas: &'a mut Canvas,
+    viewport: Viewport,
+}
+
+impl ViewportCanvas<'_> {
+    /// Gets the viewport used to map coordinates.
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Draws a shape given in world coordinates. Like with
+    /// `Canvas::draw_transformed`, circles keep their shape, so their radius
+    /// is only approximated if the viewport scales x and y differently.
+    pub fn draw(&mut self, object: impl Transform, style: Style) {
+        self.canvas
+            .draw_transformed(object, style, self.viewport.transform());
+    }
+
+    /// Plots a single point given in world coordinates. See `Canvas::plot`.
+    pub fn plot(&mut self, p: impl ToCoords, brightness: usize) {
+        self.canvas.plot(self.viewport.world_to_dot(p), brightness);
+    }
+}
+
+impl Canvas {
+    /// Borrows the canvas through `viewport`, so that shapes can be drawn
+    /// in world coordinates. See `Viewport`.
+    pub fn with_viewport(&mut self, viewport: Viewport) -> ViewportCanvas<'_> {
+        ViewportCanvas {
+            canvas: self,
+            viewport,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::geometry::{Circle, Line, Rect};
+
+    #[test]
+    fn world_to_dot() {
+        let viewport = Viewport::new((-1.0, -1.0, 2.0, 2.0), (21, 11));
+        assert_eq!(viewport.world_to_dot((-1.0, -1.0)), (0, 0));
+        assert_eq!(viewport.world_to_dot((0.0, 0.0)), (10, 5));
+        assert_eq!(viewport.world_to_dot((1.0, 1.0)), (20, 10));
+        assert_eq!(viewport.world_to_dot((0.5, -0.6)), (15, 2));
+
+        let flipped = viewport.flip_y(true);
+        assert_eq!(flipped.world_to_dot((-1.0, -1.0)), (0, 10));
+        assert_eq!(flipped.world_to_dot((0.0, 0.0)), (10, 5));
+        assert_eq!(flipped.world_to_dot((1.0, 1.0)), (20, 0));
+    }
+
+    #[test]
+    fn draw_in_world_space() {
+        let mut world = Canvas::with_dot_size(8, 8);
+        let viewport = Viewport::new((0.0, 0.0, 1.0, 1.0), world.dot_size()).flip_y(true);
+        let mut view = world.with_viewport(viewport);
+        view.draw(Line::new((0.0, 0.0), (1.0, 1.0)), Style::outlined());
+        view.plot((1.0, 0.0), Style::MAX_BRIGHTNESS);
+
+        let mut plain = Canvas::with_dot_size(8, 8);
+        plain.draw(Line::new((0, 7), (7, 0)), Style::outlined());
+        plain.plot((7, 7), Style::MAX_BRIGHTNESS);
+        assert_eq!(world.display(), plain.display());
+
+        let mut world = Canvas::with_dot_size(8, 8);
+        let viewport = Viewport::new((10.0, 10.0, 70.0, 70.0), world.dot_size());
+        world
+            .with_viewport(viewport)
+            .draw(Rect::new((20.0, 20.0), (40.0, 40.0)), Style::filled());
+        // The world rect spans dots 1 to 5.
+        for (x, y) in [(1, 1), (4, 1), (3, 3), (4, 4)] {
+            assert!(world.is_set((x, y)), "({x}, {y})");
+        }
+        for (x, y) in [(0, 0), (0, 3), (6, 3), (3, 6)] {
+            assert!(!world.is_set((x, y)), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn fractional_world_shapes() {
+        // In a unit world, every shape is smaller than a world unit, so
+        // rounding before the transform would collapse them.
+        let viewport = Viewport::new((0.0, 0.0, 1.0, 1.0), (8, 8));
+        let draw = |shape: &dyn Fn(&mut ViewportCanvas)| {
+            let mut canvas = Canvas::with_dot_size(8, 8);
+            shape(&mut canvas.with_viewport(viewport));
+            canvas
+        };
+
+        let rect = draw(&|view| view.draw(Rect::new((0.25, 0.25), (0.5, 0.5)), Style::filled()));
+        let mut plain = Canvas::with_dot_size(8, 8);
+        plain.draw(Rect::from_corners((2, 2), (4, 4)), Style::filled());
+        assert_eq!(rect.display(), plain.display());
+
+        let circle = draw(&|view| view.draw(Circle::new((0.5, 0.5), 0.3), Style::filled()));
+        let mut plain = Canvas::with_dot_size(8, 8);
+        plain.draw(Circle::new((4, 4), 2), Style::filled());
+        assert_eq!(circle.display(), plain.display());
+    }
+}
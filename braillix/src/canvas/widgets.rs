@@ -0,0 +1,166 @@
+use super::{
+    coords::ToCoords,
+    geometry::{Line, Rect},
+    Canvas, Style,
+};
+
+impl Canvas {
+    /// Draws a square checkbox `size` dots wide with its top-left corner at
+    /// `at`. The box is drawn with the style's outline and fill, and when
+    /// `checked`, a checkmark is drawn inside it with the outline brightness.
+    pub fn draw_checkbox(&mut self, at: impl ToCoords, size: i32, checked: bool, style: Style) {
+        let (x, y) = at.to_coords_i32();
+        let outline = style.outline;
+
+        self.draw(Rect::new((x, y), (size, size)), style);
+
+        let Some(brightness) = outline.filter(|_| checked) else {
+            return;
+        };
+
+        // The checkmark sits one dot inside the border.
+        let (left, top) = (x + 2, y + 2);
+        let (right, bottom) = (x + size - 3, y + size - 3);
+        if right < left || bottom < top {
+            return;
+        }
+
+        let elbow_x = left + (right - left) / 3;
+        let elbow = (elbow_x, bottom);
+        let start = (left, (bottom - (elbow_x - left)).max(top));
+
+        self.draw(
+            Line::new(start, elbow),
+            Style::outlined_with_brightness(brightness),
+        );
+        self.draw(
+            Line::new(elbow, (right, top)),
+            Style::outlined_with_brightness(brightness),
+        );
+    }
+
+    /// Draws a toggle switch with its top-left corner at `at` and the given
+    /// `(width, height)`. The track is drawn with the style's outline and
+    /// fill, and the knob is drawn with the outline brightness on the left
+    /// when off or on the right when `on`.
+    pub fn draw_toggle(&mut self, at: impl ToCoords, size: impl ToCoords, on: bool, style: Style) {
+        let (x, y) = at.to_coords_i32();
+        let (w, h) = size.to_coords_i32();
+        let outline = style.outline;
+
+        self.draw(Rect::new((x, y), (w, h)), style);
+
+        let Some(brightness) = outline else {
+            return;
+        };
+
+        // The knob is a square one dot inside the border.
+        let knob = (h - 4).min(w - 4);
+        if knob <= 0 {
+            return;
+        }
+
+        let knob_x = if on { x + w - 2 - knob } else { x + 2 };
+        self.draw(
+            Rect::new((knob_x, y + 2), (knob, knob)),
+            Style::filled_with_brightness(brightness),
+        );
+    }
+
+    /// Draws a frame `thickness` dots thick around the edge of the canvas
+    /// with the style's outline brightness, and fills the area inside it
+    /// with the style's fill. The frame grows inward, so on a canvas less
+    /// than `2 * thickness` dots across, it meets in the middle and covers
+    /// that whole dimension. Nothing is drawn for a thickness below 1.
+    pub fn draw_border(&mut self, thickness: i32, style: Style) {
+        if thickness < 1 {
+            return;
+        }
+
+        let (w, h) = self.dot_size();
+        let (w, h) = (w as i32, h as i32);
+        let t = thickness.min(w.max(h));
+
+        if let Some(brightness) = style.outline {
+            let band = Style::filled_with_brightness(brightness);
+            self.draw(Rect::new((0, 0), (w, t)), band);
+            self.draw(Rect::new((0, h - t), (w, t)), band);
+            self.draw(Rect::new((0, 0), (t, h)), band);
+            self.draw(Rect::new((w - t, 0), (t, h)), band);
+        }
+
+        let inner = (w - 2 * t, h - 2 * t);
+        if style.fill.is_some() && inner.0 > 0 && inner.1 > 0 {
+            self.draw(
+                Rect::new((t, t), inner),
+                Style {
+                    outline: None,
+                    ..style
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkbox() {
+        let mut checked = Canvas::with_dot_size(12, 12);
+        checked.draw_checkbox((1, 1), 10, true, Style::outlined());
+
+        let mut unchecked = Canvas::with_dot_size(12, 12);
+        unchecked.draw_checkbox((1, 1), 10, false, Style::outlined());
+
+        // The border is the same either way.
+        for i in 1..11 {
+            for (x, y) in [(i, 1), (i, 10), (1, i), (10, i)] {
+                assert!(checked.display().is_set(x, y));
+                assert!(unchecked.display().is_set(x, y));
+            }
+        }
+
+        // The checkmark's elbow and tips.
+        for (x, y) in [(3, 7), (4, 8), (8, 3)] {
+            assert!(checked.display().is_set(x, y), "({x}, {y})");
+        }
+        for y in 2..10 {
+            for x in 2..10 {
+                assert!(!unchecked.display().is_set(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn toggle() {
+        let knob_at = |on: bool| {
+            let mut canvas = Canvas::with_dot_size(16, 8);
+            canvas.draw_toggle((0, 0), (16, 8), on, Style::outlined());
+            (
+                canvas.display().is_set(3, 3),
+                canvas.display().is_set(12, 3),
+            )
+        };
+
+        assert_eq!(knob_at(false), (true, false));
+        assert_eq!(knob_at(true), (false, true));
+    }
+
+    #[test]
+    fn border() {
+        let mut canvas = Canvas::with_dot_size(8, 8);
+        canvas.draw_border(2, Style::outlined().fill_off());
+        assert_eq!(canvas.to_string(), "⣿⠛⠛⣿\n⣿⣤⣤⣿");
+
+        // Too thick to leave an inside, so it covers the whole canvas.
+        let mut tiny = Canvas::with_dot_size(4, 8);
+        tiny.draw_border(3, Style::outlined());
+        assert!(tiny.display().is_full());
+
+        let mut unchanged = Canvas::with_dot_size(4, 8);
+        unchanged.draw_border(0, Style::outlined());
+        assert!(unchanged.display().is_empty());
+    }
+}
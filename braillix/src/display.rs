@@ -6,10 +6,27 @@
 //!
 //! For more advanced drawing, see `Canvas`.
 
-use std::fmt;
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Write},
+    hash::Hash,
+    ops::{BitAnd, BitOr, BitXor},
+};
+#[cfg(feature = "std")]
+use std::hash::{DefaultHasher, Hasher};
 
 /// A low-level buffer for braille drawing.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// With the `serde` feature, a display serializes as its output `width` and
+/// `height` along with its `cells`, and deserializing checks that there is
+/// exactly one cell for every character.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DisplayData")
+)]
 pub struct Display {
     width: usize,
     height: usize,
@@ -26,6 +43,7 @@ impl Display {
     /// # Panics
     /// This function panics if the width and height do not meet
     /// the above constraints.
+    #[allow(clippy::manual_is_multiple_of)]
     pub fn with_dot_size(width: usize, height: usize) -> Self {
         // TODO: implement non-snapped grid sizes by using partial cells
         assert!(width % 2 == 0, "width must be a multiple of 2");
@@ -34,6 +52,9 @@ impl Display {
     }
 
     /// Creates a new `Display` with the given output (character) dimensions.
+    ///
+    /// Zero dimensions are allowed and give a display with no cells, which
+    /// renders as nothing. See `try_with_output_size` to reject them.
     pub fn with_output_size(width: usize, height: usize) -> Self {
         let cells = vec![0; width * height];
         Self {
@@ -43,6 +64,57 @@ impl Display {
         }
     }
 
+    /// Parses braille text, such as the output of `lines` joined with
+    /// newlines, back into a `Display`. Each line becomes a row of cells.
+    ///
+    /// Returns an error if a character is not a braille pattern (including
+    /// spaces, since blank cells are `'⠀'`), or if the lines differ in
+    /// length.
+    pub fn from_str_braille(s: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (line, text) in s.lines().enumerate() {
+            let start = cells.len();
+            for (column, c) in text.chars().enumerate() {
+                let cell = braille_util::get_index(c).ok_or(ParseError::InvalidChar {
+                    line,
+                    column,
+                    c,
+                })?;
+                cells.push(cell);
+            }
+
+            let length = cells.len() - start;
+            let expected = *width.get_or_insert(length);
+            if length != expected {
+                return Err(ParseError::RaggedLine {
+                    line,
+                    expected,
+                    length,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Like `with_output_size`, but returns an error if either dimension is
+    /// zero, since an empty display is usually the result of a layout bug.
+    pub fn try_with_output_size(width: usize, height: usize) -> Result<Self, SizeError> {
+        match (width, height) {
+            (0, _) => Err(SizeError::ZeroWidth),
+            (_, 0) => Err(SizeError::ZeroHeight),
+            _ => Ok(Self::with_output_size(width, height)),
+        }
+    }
+
     /// Gets the width of the display in dots.
     pub fn dot_width(&self) -> usize {
         self.width * 2
@@ -75,13 +147,139 @@ impl Display {
 
     /// Returns an iterator over the lines of the display as `String`s.
     pub fn lines(&self) -> DisplayLines<'_> {
+        self.lines_with_charset(Charset::Braille)
+    }
+
+    /// Like `lines`, but with each cell drawn using the given `Charset`.
+    /// Every charset uses one character per cell, so the output size is
+    /// the same as with braille.
+    pub fn lines_with_charset(&self, charset: Charset) -> DisplayLines<'_> {
         DisplayLines {
             display: self,
+            charset,
             index: 0,
         }
     }
 
+    /// Returns an iterator over every cell as `(x, y, char)` in output
+    /// (character) coordinates, row by row from the top-left. Blank cells
+    /// are yielded as `'⠀'` (U+2800).
+    ///
+    /// Unlike `lines`, this builds no strings, so it suits frontends that
+    /// position each glyph themselves, such as GPU text renderers or web
+    /// canvases. For a `Canvas`, use `canvas.display().cells_as_chars()`.
+    pub fn cells_as_chars(&self) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, &cell)| (i % self.width, i / self.width, braille_util::get_char(cell)))
+    }
+
+    /// Gets how many of the 8 dots in the cell at `(cx, cy)` are set, in
+    /// output (character) coordinates. Frontends can map this to a shade
+    /// or color.
+    ///
+    /// # Panics
+    /// The cell must be inside the display.
+    pub fn cell_coverage(&self, cx: usize, cy: usize) -> u8 {
+        assert!(cx < self.width && cy < self.height, "cell out of range");
+        self.cells[self.coord_to_index(cx, cy)].count_ones() as u8
+    }
+
+    /// Gets the coverage of every cell (see `cell_coverage`), row by row
+    /// from the top-left.
+    pub fn coverage_grid(&self) -> Vec<u8> {
+        self.cells.iter().map(|c| c.count_ones() as u8).collect()
+    }
+
+    /// Renders the display as an RGBA pixel buffer with one pixel per dot,
+    /// colored `on` where the dot is set and `off` where it isn't. Returns
+    /// the buffer (rows from top to bottom) along with its width and height.
+    pub fn to_pixels(&self, on: [u8; 4], off: [u8; 4]) -> (Vec<u8>, usize, usize) {
+        let (w, h) = self.dot_size();
+        let pixels = (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .flat_map(|(x, y)| if self.is_set(x, y) { on } else { off })
+            .collect();
+        (pixels, w, h)
+    }
+
+    /// Renders the display as an SVG document with a `<circle>` of radius
+    /// `dot_radius` for every set dot. Dots are `spacing` units apart, with
+    /// each one centered in its `spacing`-sized square of the grid, and the
+    /// `viewBox` covers the whole grid so the image scales cleanly.
+    pub fn to_svg(&self, dot_radius: f64, spacing: f64) -> String {
+        let (w, h) = self.dot_size();
+        let (vw, vh) = (w as f64 * spacing, h as f64 * spacing);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {vw} {vh}\" \
+             width=\"{vw}\" height=\"{vh}\">\n"
+        );
+        for y in 0..h {
+            for x in 0..w {
+                if self.is_set(x, y) {
+                    let (cx, cy) = ((x as f64 + 0.5) * spacing, (y as f64 + 0.5) * spacing);
+                    // Writing to a `String` can't fail.
+                    let _ = writeln!(
+                        svg,
+                        "  <circle cx=\"{cx}\" cy=\"{cy}\" r=\"{dot_radius}\"/>"
+                    );
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Computes a hash of the display's size and contents, suitable for
+    /// keying a cache of rendered frames. Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns an iterator over the cells that differ from `previous`, as
+    /// `(x, y, char)` in output (character) coordinates. If the displays are
+    /// not the same size, every cell is considered changed.
+    pub fn diff<'a>(
+        &'a self,
+        previous: &'a Display,
+    ) -> impl Iterator<Item = (usize, usize, char)> + 'a {
+        self.diff_region(previous, (0, 0), self.output_size())
+    }
+
+    /// Like `diff`, but only compares the cells in the region at `(x, y)`
+    /// with size `(width, height)`, in output coordinates. The region is
+    /// clipped to the display.
+    pub fn diff_region<'a>(
+        &'a self,
+        previous: &'a Display,
+        (x, y): (usize, usize),
+        (width, height): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize, char)> + 'a {
+        let same_size = self.output_size() == previous.output_size();
+        let x1 = x.saturating_add(width).min(self.width);
+        let y1 = y.saturating_add(height).min(self.height);
+        let (x0, y0) = (x.min(x1), y.min(y1));
+
+        (y0..y1)
+            .flat_map(move |cy| (x0..x1).map(move |cx| (cx, cy)))
+            .filter_map(move |(cx, cy)| {
+                let i = self.coord_to_index(cx, cy);
+                let cell = self.cells[i];
+                (!same_size || cell != previous.cells[i])
+                    .then(|| (cx, cy, braille_util::get_char(cell)))
+            })
+    }
+
     /// Checks if the given dot is set in the display.
+    ///
+    /// # Panics
+    /// The dot must be inside the display. Out-of-range coordinates may
+    /// panic or affect a dot in another row. See `checked_is_set`.
     pub fn is_set(&self, x: usize, y: usize) -> bool {
         let ((cell_x, subcell_x), (cell_y, subcell_y)) = Self::parse_coord(x, y);
 
@@ -90,6 +288,10 @@ impl Display {
     }
 
     /// Sets the given dot in the display.
+    ///
+    /// # Panics
+    /// The dot must be inside the display. Out-of-range coordinates may
+    /// panic or affect a dot in another row. See `checked_set`.
     pub fn set(&mut self, x: usize, y: usize) {
         let ((cell_x, subcell_x), (cell_y, subcell_y)) = Self::parse_coord(x, y);
 
@@ -98,6 +300,10 @@ impl Display {
     }
 
     /// Unsets the given dot in the display.
+    ///
+    /// # Panics
+    /// The dot must be inside the display. Out-of-range coordinates may
+    /// panic or affect a dot in another row. See `checked_unset`.
     pub fn unset(&mut self, x: usize, y: usize) {
         let ((cell_x, subcell_x), (cell_y, subcell_y)) = Self::parse_coord(x, y);
 
@@ -105,25 +311,366 @@ impl Display {
         braille_util::unset_coord(&mut self.cells[i], subcell_x, subcell_y);
     }
 
+    /// Like `set`, but returns whether the dot changed, i.e. whether it was
+    /// unset before. This makes it cheap to track what needs redrawing.
+    pub fn set_changed(&mut self, x: usize, y: usize) -> bool {
+        let was_set = self.is_set(x, y);
+        self.set(x, y);
+        !was_set
+    }
+
+    /// Like `unset`, but returns whether the dot changed, i.e. whether it
+    /// was set before. See `set_changed`.
+    pub fn unset_changed(&mut self, x: usize, y: usize) -> bool {
+        let was_set = self.is_set(x, y);
+        self.unset(x, y);
+        was_set
+    }
+
+    /// Toggles the given dot in the display.
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        let ((cell_x, subcell_x), (cell_y, subcell_y)) = Self::parse_coord(x, y);
+
+        let i = self.coord_to_index(cell_x, cell_y);
+        braille_util::toggle_coord(&mut self.cells[i], subcell_x, subcell_y);
+    }
+
+    /// Like `is_set`, but returns `None` if the dot is outside the display.
+    pub fn checked_is_set(&self, x: usize, y: usize) -> Option<bool> {
+        self.contains(x, y).then(|| self.is_set(x, y))
+    }
+
+    /// Like `set`, but does nothing and returns `None` if the dot is outside
+    /// the display.
+    pub fn checked_set(&mut self, x: usize, y: usize) -> Option<()> {
+        self.contains(x, y).then(|| self.set(x, y))
+    }
+
+    /// Like `unset`, but does nothing and returns `None` if the dot is
+    /// outside the display.
+    pub fn checked_unset(&mut self, x: usize, y: usize) -> Option<()> {
+        self.contains(x, y).then(|| self.unset(x, y))
+    }
+
+    /// Checks whether no dots are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|&cell| cell == 0)
+    }
+
+    /// Checks whether every dot is set. A display is always made of whole
+    /// cells, so this means every cell is full. See `Canvas::is_full` for
+    /// canvases whose size doesn't fill their last cells.
+    pub fn is_full(&self) -> bool {
+        self.cells.iter().all(|&cell| cell == 0xff)
+    }
+
+    /// Checks whether every dot in the rectangle with its top-left corner at
+    /// `(x, y)` and the given `width` and `height`, clipped to the display,
+    /// is set. See `set_rect`.
+    pub(crate) fn is_rect_full(&self, x: usize, y: usize, width: usize, height: usize) -> bool {
+        self.rect_masks(x, y, width, height)
+            .all(|(i, mask)| self.cells[i] & mask == mask)
+    }
+
+    /// Sets every dot in `dots`. Dots in the same cell are combined so that
+    /// each cell is written once, which is faster than calling `set` for
+    /// every dot when there are many of them. Dots outside the display are
+    /// ignored.
+    pub fn set_many(&mut self, dots: impl IntoIterator<Item = (usize, usize)>) {
+        for (i, mask) in self.cell_masks(dots) {
+            braille_util::set_mask(&mut self.cells[i], mask);
+        }
+    }
+
+    /// Unsets every dot in `dots`. See `set_many`.
+    pub fn unset_many(&mut self, dots: impl IntoIterator<Item = (usize, usize)>) {
+        for (i, mask) in self.cell_masks(dots) {
+            braille_util::unset_mask(&mut self.cells[i], !mask);
+        }
+    }
+
+    /// Sets every dot in the rectangle with its top-left corner at `(x, y)`
+    /// and the given `width` and `height`, clipped to the display. Whole
+    /// cells inside the rectangle are filled at once and only the cells on
+    /// its edges are masked, so this is much faster than setting each dot,
+    /// with the same result.
+    pub fn set_rect(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        for (i, mask) in self.rect_masks(x, y, width, height) {
+            braille_util::set_mask(&mut self.cells[i], mask);
+        }
+    }
+
+    /// Same as `set_rect`.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        self.set_rect(x, y, width, height);
+    }
+
+    /// Unsets every dot in the rectangle with its top-left corner at
+    /// `(x, y)` and the given `width` and `height`, clipped to the display.
+    /// See `set_rect`.
+    pub fn clear_rect(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        for (i, mask) in self.rect_masks(x, y, width, height) {
+            braille_util::unset_mask(&mut self.cells[i], !mask);
+        }
+    }
+
+    /// Sets every dot in row `y`. See `set_rect`.
+    pub fn fill_row(&mut self, y: usize) {
+        self.set_rect(0, y, self.dot_width(), 1);
+    }
+
+    /// Sets every dot in column `x`. See `set_rect`.
+    pub fn fill_col(&mut self, x: usize) {
+        self.set_rect(x, 0, 1, self.dot_height());
+    }
+
     /// Clears the display by unsetting all of its dots.
     pub fn clear(&mut self) {
         self.cells.fill(0);
     }
 
+    /// Fills the display by setting all of its dots.
+    pub fn fill(&mut self) {
+        self.cells.fill(0xff);
+    }
+
+    /// Returns a copy of the display flipped along its main diagonal, so
+    /// that the dot at `(x, y)` moves to `(y, x)`. The new display is
+    /// `dot_height` dots wide and `dot_width` dots tall, rounded up to a
+    /// whole number of cells.
+    pub fn transposed(&self) -> Display {
+        let (w, h) = self.dot_size();
+        let mut transposed = Display::with_dot_size(h, w.next_multiple_of(4));
+
+        for y in 0..h {
+            for x in 0..w {
+                if self.is_set(x, y) {
+                    transposed.set(y, x);
+                }
+            }
+        }
+
+        transposed
+    }
+
     /// Splits dot coordinates into the cell coordinates and sub-cell coordinates.
     fn parse_coord(x: usize, y: usize) -> ((usize, usize), (usize, usize)) {
         ((x / 2, x % 2), (y / 4, y % 4))
     }
 
+    /// Groups the in-bounds `dots` by cell, as pairs of a cell index and the
+    /// mask of that cell's dots.
+    fn cell_masks(&self, dots: impl IntoIterator<Item = (usize, usize)>) -> Vec<(usize, u8)> {
+        let mut masks: Vec<_> = dots
+            .into_iter()
+            .filter(|&(x, y)| self.contains(x, y))
+            .map(|(x, y)| {
+                let ((cell_x, subcell_x), (cell_y, subcell_y)) = Self::parse_coord(x, y);
+                let mask = 1 << (4 * subcell_x + subcell_y);
+                (self.coord_to_index(cell_x, cell_y), mask)
+            })
+            .collect();
+        masks.sort_unstable_by_key(|&(i, _)| i);
+
+        masks.dedup_by(|(i, mask), (prev_i, prev_mask)| {
+            let same_cell = i == prev_i;
+            if same_cell {
+                *prev_mask |= *mask;
+            }
+            same_cell
+        });
+        masks
+    }
+
+    /// Gets the cells overlapping a rectangle of dots, clipped to the
+    /// display, as pairs of a cell index and the mask of that cell's dots
+    /// inside the rectangle.
+    fn rect_masks(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = (usize, u8)> {
+        let (w, h) = self.dot_size();
+        let row_length = self.width;
+        let (x0, x1) = (x.min(w), x.saturating_add(width).min(w));
+        let (y0, y1) = (y.min(h), y.saturating_add(height).min(h));
+        let (cells_x, cells_y) = match x0 < x1 && y0 < y1 {
+            true => (x0 / 2..x1.div_ceil(2), y0 / 4..y1.div_ceil(4)),
+            false => (0..0, 0..0),
+        };
+
+        cells_y.flat_map(move |cell_y| {
+            // The rows of this cell inside the rectangle, as a column mask.
+            let top = y0.max(cell_y * 4) - cell_y * 4;
+            let bottom = y1.min(cell_y * 4 + 4) - cell_y * 4;
+            let column: u8 = (0xf >> (4 - (bottom - top))) << top;
+
+            cells_x.clone().map(move |cell_x| {
+                let left = x0.max(cell_x * 2) - cell_x * 2;
+                let right = x1.min(cell_x * 2 + 2) - cell_x * 2;
+                let mask =
+                    (left..right).fold(0, |mask, subcell_x| mask | column << (4 * subcell_x));
+                (cell_y * row_length + cell_x, mask)
+            })
+        })
+    }
+
+    /// Checks whether a dot is inside the display.
+    fn contains(&self, x: usize, y: usize) -> bool {
+        let (w, h) = self.dot_size();
+        x < w && y < h
+    }
+
     /// Turns cell coordinates into their corresponding internal array index.
     fn coord_to_index(&self, x: usize, y: usize) -> usize {
         y * self.width + x
     }
 }
 
-/// Iterator over the lines of braille text in the display.
+/// The reasons a size can be rejected by `try_with_output_size`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeError {
+    /// The width is zero.
+    ZeroWidth,
+    /// The height is zero.
+    ZeroHeight,
+}
+
+impl fmt::Display for SizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroWidth => write!(f, "width is zero"),
+            Self::ZeroHeight => write!(f, "height is zero"),
+        }
+    }
+}
+
+impl Error for SizeError {}
+
+/// The reasons braille text can fail to parse with
+/// `Display::from_str_braille`. Lines and columns count from 0, in
+/// characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// A character is not a braille pattern.
+    InvalidChar { line: usize, column: usize, c: char },
+    /// A line is a different length from the first line.
+    RaggedLine {
+        line: usize,
+        expected: usize,
+        length: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar { line, column, c } => {
+                write!(f, "{c:?} at line {line}, column {column} is not braille")
+            }
+            Self::RaggedLine {
+                line,
+                expected,
+                length,
+            } => write!(
+                f,
+                "line {line} is {length} characters long, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The unvalidated form of a deserialized `Display`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DisplayData {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DisplayData> for Display {
+    type Error = String;
+
+    fn try_from(data: DisplayData) -> Result<Self, Self::Error> {
+        let expected = data.width.checked_mul(data.height);
+        if expected != Some(data.cells.len()) {
+            return Err(format!(
+                "expected {} x {} cells, found {}",
+                data.width,
+                data.height,
+                data.cells.len()
+            ));
+        }
+
+        Ok(Self {
+            width: data.width,
+            height: data.height,
+            cells: data.cells,
+        })
+    }
+}
+
+/// The characters used to draw each 2x4 dot cell as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Charset {
+    /// Braille patterns, which show every dot.
+    #[default]
+    Braille,
+
+    /// Quadrant block characters (`▘▝▀▖…`), which split the cell into a 2x2
+    /// grid. A quadrant is filled if any of its two dots is set.
+    QuadrantBlocks,
+
+    /// `#` if any dot in the cell is set and `.` otherwise.
+    Ascii,
+
+    /// Braille dot ordering shifted to start at the given code point instead
+    /// of U+2800, for fonts or devices that map the 256 cell patterns to a
+    /// different range. Cells that would land on an invalid code point are
+    /// drawn as U+FFFD.
+    BrailleFrom(u32),
+}
+
+impl Charset {
+    /// Quadrant blocks, indexed by bits for the top-left (1), top-right (2),
+    /// bottom-left (4), and bottom-right (8) quadrants.
+    const QUADRANTS: [char; 16] = [
+        ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+    ];
+
+    fn cell_char(self, cell: u8) -> char {
+        match self {
+            Charset::Braille => braille_util::get_char(cell),
+            Charset::QuadrantBlocks => {
+                // Dot (x, y) is bit `4 * x + y`, so each quadrant is a pair
+                // of adjacent bits.
+                let quadrant = |mask: u8, bit: usize| ((cell & mask != 0) as usize) << bit;
+                let i =
+                    quadrant(0x03, 0) | quadrant(0x30, 1) | quadrant(0x0c, 2) | quadrant(0xc0, 3);
+                Self::QUADRANTS[i]
+            }
+            Charset::Ascii => match cell {
+                0 => '.',
+                _ => '#',
+            },
+            Charset::BrailleFrom(base) => base
+                .checked_add(braille_util::get_offset(cell))
+                .and_then(char::from_u32)
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        }
+    }
+}
+
+/// Iterator over the lines of text in the display.
 pub struct DisplayLines<'a> {
     display: &'a Display,
+    charset: Charset,
     index: usize,
 }
 
@@ -139,7 +686,7 @@ impl Iterator for DisplayLines<'_> {
         let end = start + self.display.width;
         let line = self.display.cells[start..end]
             .iter()
-            .map(|&i| braille_util::get_char(i))
+            .map(|&i| self.charset.cell_char(i))
             .collect();
 
         self.index += 1;
@@ -165,8 +712,64 @@ impl fmt::Display for Display {
     }
 }
 
+impl Display {
+    /// Combines two displays of the same size cell by cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the displays differ in size.
+    fn combine(&self, other: &Display, op: impl Fn(u8, u8) -> u8) -> Display {
+        assert_eq!(
+            self.output_size(),
+            other.output_size(),
+            "cannot combine displays of different sizes"
+        );
+
+        Display {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .cells
+                .iter()
+                .zip(&other.cells)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+        }
+    }
+}
+
+/// Gets a display with the dots that are set in either display. Both must
+/// be the same size, or this panics.
+impl BitOr for &Display {
+    type Output = Display;
+
+    fn bitor(self, rhs: Self) -> Display {
+        self.combine(rhs, |a, b| a | b)
+    }
+}
+
+/// Gets a display with the dots that are set in both displays. Both must be
+/// the same size, or this panics.
+impl BitAnd for &Display {
+    type Output = Display;
+
+    fn bitand(self, rhs: Self) -> Display {
+        self.combine(rhs, |a, b| a & b)
+    }
+}
+
+/// Gets a display with the dots that are set in exactly one of the displays.
+/// Both must be the same size, or this panics.
+impl BitXor for &Display {
+    type Output = Display;
+
+    fn bitxor(self, rhs: Self) -> Display {
+        self.combine(rhs, |a, b| a ^ b)
+    }
+}
+
 /// Helper functions for working with the braille characters and their `u8` lookup table indices.
-mod braille_util {
+pub(crate) mod braille_util {
     // Lookup table generation code based on:
     // https://github.com/766F6964/dotdotdot/blob/master/src/renderer.c
     const BRAILLE_UNICODE_OFFSET: u32 = 0x2800;
@@ -207,6 +810,32 @@ mod braille_util {
         LOOKUP_TABLE[i as usize]
     }
 
+    /// Reverses `get_char`, returning `None` if `c` is not a braille
+    /// character.
+    pub const fn get_index(c: char) -> Option<u8> {
+        let offset = (c as u32).wrapping_sub(BRAILLE_UNICODE_OFFSET);
+        if offset > 0xff {
+            return None;
+        }
+
+        let mut i = 0;
+        let mut j = 0;
+        while j < 8 {
+            if offset & TRANSFORMATION_MATRIX[j] != 0 {
+                i |= 1 << j;
+            }
+            j += 1;
+        }
+
+        Some(i)
+    }
+
+    /// Gets the offset of the braille character for `i` from the start of
+    /// the braille block.
+    pub const fn get_offset(i: u8) -> u32 {
+        get_char(i) as u32 - BRAILLE_UNICODE_OFFSET
+    }
+
     /// Checks if a given dot is set in `i`.
     pub fn is_set(i: u8, x: usize, y: usize) -> bool {
         let mask = 1 << (4 * x + y);
@@ -243,6 +872,15 @@ mod braille_util {
         unset_mask(i, mask);
     }
 
+    /// Toggles a braille dot on an index ref.
+    ///
+    /// # Bounds:
+    /// - 0 <= x < 2
+    /// - 0 <= y < 4
+    pub fn toggle_coord(i: &mut u8, x: usize, y: usize) {
+        *i ^= 1 << (4 * x + y);
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -252,6 +890,16 @@ mod braille_util {
             assert_eq!(get_char(0b1110_1111), '⣷');
         }
 
+        #[test]
+        fn reverse_lookup() {
+            for i in 0..=255 {
+                assert_eq!(get_index(get_char(i)), Some(i));
+            }
+            assert_eq!(get_index('a'), None);
+            assert_eq!(get_index('\u{27ff}'), None);
+            assert_eq!(get_index('\u{2900}'), None);
+        }
+
         #[test]
         fn set() {
             let mut i: u8 = 0;
@@ -274,6 +922,18 @@ mod braille_util {
             unset_coord(&mut i, 0, 2);
             assert_eq!(get_char(i), '⡡');
         }
+
+        #[test]
+        fn toggle() {
+            let mut i: u8 = 79;
+            toggle_coord(&mut i, 0, 1);
+            toggle_coord(&mut i, 1, 0);
+            assert_eq!(get_char(i), '⡭');
+
+            toggle_coord(&mut i, 0, 1);
+            toggle_coord(&mut i, 1, 0);
+            assert_eq!(get_char(i), '⡧');
+        }
     }
 }
 
@@ -320,4 +980,337 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn changed() {
+        let mut display = Display::with_dot_size(4, 8);
+        assert!(display.set_changed(1, 6));
+        assert!(!display.set_changed(1, 6));
+        assert!(display.is_set(1, 6));
+
+        assert!(display.unset_changed(1, 6));
+        assert!(!display.unset_changed(1, 6));
+        assert!(!display.is_set(1, 6));
+    }
+
+    #[test]
+    fn checked() {
+        let mut display = Display::with_dot_size(4, 8);
+        assert_eq!(display.checked_set(3, 7), Some(()));
+        assert_eq!(display.checked_is_set(3, 7), Some(true));
+
+        // Out of range in either direction, including one past the edge
+        // which would otherwise land in the next row.
+        for (x, y) in [(4, 0), (0, 8), (usize::MAX, 3)] {
+            assert_eq!(display.checked_set(x, y), None);
+            assert_eq!(display.checked_unset(x, y), None);
+            assert_eq!(display.checked_is_set(x, y), None);
+        }
+        assert!(!display.is_set(0, 4));
+
+        assert_eq!(display.checked_unset(3, 7), Some(()));
+        assert_eq!(display.checked_is_set(3, 7), Some(false));
+    }
+
+    #[test]
+    fn set_many() {
+        let dots = [(0, 0), (1, 3), (9, 0), (0, 1), (6, 5), (100, 100)];
+
+        let mut batched = Display::with_dot_size(8, 8);
+        batched.set_many(dots);
+
+        let mut single = Display::with_dot_size(8, 8);
+        for (x, y) in dots {
+            single.checked_set(x, y);
+        }
+        assert_eq!(batched, single);
+
+        batched.unset_many([(0, 0), (6, 5), (9, 0)]);
+        single.unset(0, 0);
+        single.unset(6, 5);
+        assert_eq!(batched, single);
+        assert!(batched.is_set(0, 1) && batched.is_set(1, 3));
+    }
+
+    #[test]
+    fn from_str_braille() {
+        let mut display = Display::with_dot_size(6, 8);
+        for i in 0..6 {
+            display.set(i, i);
+            display.set(5 - i, 7);
+        }
+
+        let text = String::from(&display);
+        assert_eq!(Display::from_str_braille(&text), Ok(display.clone()));
+        assert_eq!(Display::from_str_braille(&(text + "\n")), Ok(display));
+
+        let empty = Display::from_str_braille("").unwrap();
+        assert_eq!(empty.output_size(), (0, 0));
+
+        assert_eq!(
+            Display::from_str_braille("⠀⠀\n⠁x"),
+            Err(ParseError::InvalidChar {
+                line: 1,
+                column: 1,
+                c: 'x'
+            })
+        );
+        assert_eq!(
+            Display::from_str_braille("⠀⠀\n⠁"),
+            Err(ParseError::RaggedLine {
+                line: 1,
+                expected: 2,
+                length: 1
+            })
+        );
+    }
+
+    #[test]
+    fn try_with_output_size() {
+        assert_eq!(
+            Display::try_with_output_size(3, 2),
+            Ok(Display::with_output_size(3, 2))
+        );
+        assert_eq!(
+            Display::try_with_output_size(0, 2),
+            Err(SizeError::ZeroWidth)
+        );
+        assert_eq!(
+            Display::try_with_output_size(3, 0),
+            Err(SizeError::ZeroHeight)
+        );
+        assert_eq!(
+            Display::try_with_output_size(0, 0),
+            Err(SizeError::ZeroWidth)
+        );
+    }
+
+    #[test]
+    fn empty_and_full() {
+        let mut display = Display::with_dot_size(4, 8);
+        assert!(display.is_empty() && !display.is_full());
+
+        display.set(3, 7);
+        assert!(!display.is_empty() && !display.is_full());
+
+        display.fill();
+        assert!(!display.is_empty() && display.is_full());
+
+        display.unset(0, 0);
+        assert!(!display.is_full());
+
+        let nothing = Display::with_output_size(0, 0);
+        assert!(nothing.is_empty() && nothing.is_full());
+    }
+
+    #[test]
+    fn rect_operations() {
+        let rects = [
+            (0, 0, 8, 8),
+            (1, 1, 6, 6),
+            (3, 2, 1, 5),
+            (5, 6, 100, 100),
+            (2, 3, 0, 4),
+            (9, 0, 2, 2),
+        ];
+        for (x, y, w, h) in rects {
+            let mut filled = Display::with_dot_size(8, 8);
+            filled.set_rect(x, y, w, h);
+            let mut cleared = Display::with_dot_size(8, 8);
+            cleared.fill();
+            cleared.clear_rect(x, y, w, h);
+
+            let mut set = Display::with_dot_size(8, 8);
+            let mut unset = Display::with_dot_size(8, 8);
+            unset.fill();
+            for dy in y..y + h {
+                for dx in x..x + w {
+                    set.checked_set(dx, dy);
+                    unset.checked_unset(dx, dy);
+                }
+            }
+            assert_eq!(filled, set, "({x}, {y}, {w}, {h})");
+            assert_eq!(cleared, unset, "({x}, {y}, {w}, {h})");
+        }
+
+        let mut display = Display::with_dot_size(4, 8);
+        display.fill_row(5);
+        display.fill_col(2);
+        assert_eq!(display.lines().collect::<Vec<_>>(), vec!["⠀⡇", "⠒⡗"]);
+
+        let mut filled = Display::with_dot_size(8, 8);
+        filled.fill_rect(1, 2, 5, 3);
+        let mut set = Display::with_dot_size(8, 8);
+        set.set_rect(1, 2, 5, 3);
+        assert_eq!(filled, set);
+    }
+
+    #[test]
+    fn transposed() {
+        let mut display = Display::with_dot_size(6, 4);
+        let dots = [(0, 0), (5, 0), (1, 3), (4, 2)];
+        for (x, y) in dots {
+            display.set(x, y);
+        }
+
+        let transposed = display.transposed();
+        assert_eq!(transposed.dot_size(), (4, 8));
+        for y in 0..8 {
+            for x in 0..4 {
+                assert_eq!(transposed.is_set(x, y), dots.contains(&(y, x)));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fingerprint() {
+        let mut a = Display::with_dot_size(8, 8);
+        let mut b = Display::with_dot_size(8, 8);
+        a.set(3, 5);
+        b.set(3, 5);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+
+        b.set(4, 5);
+        assert_ne!(a.fingerprint(), b.fingerprint());
+
+        // Same (empty) contents, different shape.
+        let wide = Display::with_dot_size(16, 4);
+        let tall = Display::with_dot_size(4, 16);
+        assert_ne!(wide.fingerprint(), tall.fingerprint());
+    }
+
+    #[test]
+    fn diff() {
+        let previous = Display::with_dot_size(8, 8);
+        let mut display = previous.clone();
+        display.set(0, 0);
+        display.set(7, 7);
+
+        let changed: Vec<_> = display.diff(&previous).collect();
+        assert_eq!(changed, vec![(0, 0, '⠁'), (3, 1, '⢀')]);
+
+        let changed: Vec<_> = display.diff_region(&previous, (2, 1), (5, 5)).collect();
+        assert_eq!(changed, vec![(3, 1, '⢀')]);
+
+        let resized = Display::with_dot_size(2, 4);
+        assert_eq!(display.diff(&resized).count(), 8);
+    }
+
+    #[test]
+    fn cells_as_chars() {
+        let mut display = Display::with_dot_size(6, 8);
+        display.set(0, 0);
+        display.set(3, 1);
+        display.set(5, 7);
+
+        let cells: Vec<_> = display.cells_as_chars().collect();
+        assert_eq!(cells.len(), 6);
+
+        let non_blank: Vec<_> = cells.into_iter().filter(|&(_, _, c)| c != '⠀').collect();
+        assert_eq!(non_blank, vec![(0, 0, '⠁'), (1, 0, '⠐'), (2, 1, '⢀')]);
+    }
+
+    #[test]
+    fn to_pixels() {
+        let mut display = Display::with_dot_size(2, 4);
+        display.set(1, 0);
+        display.set(0, 3);
+
+        let (on, off) = ([255, 255, 255, 255], [0, 0, 0, 255]);
+        let (pixels, w, h) = display.to_pixels(on, off);
+        assert_eq!((w, h), (2, 4));
+        assert_eq!(pixels.len(), 2 * 4 * 4);
+
+        let expected: Vec<_> = [off, on, off, off, off, off, on, off].concat();
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn to_svg() {
+        let mut display = Display::with_dot_size(2, 4);
+        display.set(1, 2);
+
+        let svg = display.to_svg(0.4, 2.0);
+        assert_eq!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 4 8\" width=\"4\" height=\"8\">\n\
+             \x20 <circle cx=\"3\" cy=\"5\" r=\"0.4\"/>\n\
+             </svg>\n"
+        );
+    }
+
+    #[test]
+    fn lines_with_charset() {
+        let mut display = Display::with_dot_size(6, 4);
+        display.set(0, 0);
+        display.set(1, 3);
+        for y in 0..4 {
+            display.set(4, y);
+            display.set(5, y);
+        }
+
+        let lines = |charset| display.lines_with_charset(charset).collect::<Vec<_>>();
+        assert_eq!(lines(Charset::Braille), vec!["⢁⠀⣿"]);
+        assert_eq!(lines(Charset::QuadrantBlocks), vec!["▚ █"]);
+        assert_eq!(lines(Charset::Ascii), vec!["#.#"]);
+        assert_eq!(
+            lines(Charset::BrailleFrom(0xe000)),
+            vec!["\u{e081}\u{e000}\u{e0ff}"]
+        );
+        assert_eq!(
+            lines(Charset::BrailleFrom(0xd7ff)),
+            vec!["\u{fffd}\u{d7ff}\u{fffd}"]
+        );
+    }
+
+    #[test]
+    fn coverage() {
+        let mut display = Display::with_output_size(2, 2);
+        display.set(0, 0);
+        for y in 4..8 {
+            display.set(2, y);
+            display.set(3, y);
+        }
+
+        assert_eq!(display.cell_coverage(0, 0), 1);
+        assert_eq!(display.cell_coverage(1, 1), 8);
+        assert_eq!(display.coverage_grid(), vec![1, 0, 0, 8]);
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        let mut a = Display::with_dot_size(2, 4);
+        a.set(0, 0);
+        a.set(1, 1);
+        let mut b = Display::with_dot_size(2, 4);
+        b.set(1, 1);
+        b.set(0, 3);
+
+        assert_eq!((&a | &b).to_string(), "⡑");
+        assert_eq!((&a & &b).to_string(), "⠐");
+        assert_eq!((&a ^ &b).to_string(), "⡁");
+    }
+
+    #[test]
+    #[should_panic(expected = "different sizes")]
+    fn bitwise_size_mismatch() {
+        let _ = &Display::with_dot_size(2, 4) | &Display::with_dot_size(4, 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut display = Display::with_dot_size(4, 8);
+        display.set(1, 2);
+        display.set(3, 7);
+
+        let json = serde_json::to_string(&display).unwrap();
+        assert_eq!(json, r#"{"width":2,"height":2,"cells":[64,0,0,128]}"#);
+        assert_eq!(serde_json::from_str::<Display>(&json).unwrap(), display);
+
+        let bad = r#"{"width":2,"height":2,"cells":[64,0,0]}"#;
+        let err = serde_json::from_str::<Display>(bad).unwrap_err();
+        assert!(err.to_string().contains("expected 2 x 2 cells, found 3"));
+    }
 }
@@ -0,0 +1,51 @@
+//! # dither
+//!
+//! This module exposes the ordered dithering that `Canvas` uses, so custom
+//! rasterizers drawing straight onto a `Display` can shade dots the same
+//! way.
+//!
+//! Brightness values run from `0` to `max_brightness()`, the same scale as
+//! `Style`. A dot at `(x, y)` is set when its brightness beats the Bayer
+//! threshold for that position, which `is_set` checks directly.
+//!
+//! # Example
+//!
+//! ```
+//! use braillix::{
+//!     canvas::{geometry::Rect, Canvas, Style},
+//!     dither,
+//!     display::Display,
+//! };
+//!
+//! let half = dither::max_brightness() / 2;
+//!
+//! let mut display = Display::with_dot_size(8, 8);
+//! for y in 0..8 {
+//!     for x in 0..8 {
+//!         if dither::is_set(x, y, half, dither::DEFAULT_ORDER) {
+//!             display.set(x, y);
+//!         }
+//!     }
+//! }
+//!
+//! let mut canvas = Canvas::with_dot_size(8, 8);
+//! canvas.draw(Rect::new((0, 0), (8, 8)), Style::filled_with_brightness(half));
+//! assert_eq!(&display, canvas.display());
+//! ```
+
+pub use crate::canvas::dither::{
+    is_lit, levels, max_brightness, threshold, DitherMode, MatrixError, DEFAULT_ORDER, MAX_ORDER,
+    MAX_THRESHOLD,
+};
+
+/// Checks if the dot at (x, y) is set at `brightness` when dithered with a
+/// Bayer matrix of the given `order`, as a `Canvas` with that dither order
+/// would draw it.
+///
+/// # Panics
+///
+/// Panics if `order` is above `MAX_ORDER`.
+pub fn is_set(x: usize, y: usize, brightness: usize, order: usize) -> bool {
+    // `threshold` checks the order.
+    is_lit(brightness, threshold(x, y, order), levels(order))
+}
@@ -0,0 +1,93 @@
+//! Floating point math that `core` doesn't provide, backed by `libm` when
+//! `std` isn't available. With `std`, the inherent methods are used instead
+//! and this trait goes unused.
+
+#![cfg_attr(any(feature = "std", test), allow(dead_code))]
+
+pub(crate) trait Float {
+    fn round(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin_cos(self) -> (Self, Self)
+    where
+        Self: Sized;
+    fn atan2(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+}
+
+#[cfg(feature = "libm")]
+impl Float for f64 {
+    #[inline]
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        libm::ceil(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        libm::sincos(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        libm::pow(self, n)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Float for f32 {
+    #[inline]
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        libm::sincosf(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2f(self, other)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+}
@@ -0,0 +1,48 @@
+//! # glyph
+//!
+//! This module provides the mapping between single braille characters and
+//! their dots, for building custom buffers or reading existing braille text.
+//!
+//! Dots are given as a mask `0b7654_3210`, where bit `4 * x + y` is the dot
+//! in column `x` (`0..2`, left to right) and row `y` (`0..4`, top to bottom):
+//!
+//! ```text
+//! 0 4
+//! 1 5
+//! 2 6
+//! 3 7
+//! ```
+//!
+//! This is the same layout `Display` uses for its cells.
+
+use crate::display::braille_util;
+
+/// Gets the braille character with the dots in `mask` raised.
+///
+/// # Example
+///
+/// ```
+/// use braillix::glyph::char_from_dots;
+///
+/// assert_eq!(char_from_dots(0b0000_0000), '⠀');
+/// assert_eq!(char_from_dots(0b1001_1111), '⣏');
+/// ```
+pub const fn char_from_dots(mask: u8) -> char {
+    braille_util::get_char(mask)
+}
+
+/// Gets the mask of the dots raised in the braille character `c`, or `None`
+/// if `c` is not in the braille block (`U+2800-28FF`). This is the reverse
+/// of `char_from_dots`.
+///
+/// # Example
+///
+/// ```
+/// use braillix::glyph::dots_from_char;
+///
+/// assert_eq!(dots_from_char('⣏'), Some(0b1001_1111));
+/// assert_eq!(dots_from_char('x'), None);
+/// ```
+pub const fn dots_from_char(c: char) -> Option<u8> {
+    braille_util::get_index(c)
+}
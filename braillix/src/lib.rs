@@ -7,6 +7,21 @@
 //! low-level functionality for setting and clearing individual braille "dots",
 //! as well as a `Canvas` struct that builds on `Display` to expose simple
 //! rastering functions for drawing lines, triangles, rectangles, etc.
+//!
+//! The crate only needs `alloc`. Disable the default `std` feature to use it
+//! in `no_std` environments, and enable the `libm` feature instead to
+//! provide the floating point math that `std` normally does.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("braillix requires either the `std` or the `libm` feature");
 
 pub mod canvas;
 pub mod display;
+pub mod dither;
+pub mod glyph;
+
+mod float;
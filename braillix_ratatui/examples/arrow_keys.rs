@@ -0,0 +1,42 @@
+use std::{io, time::Duration};
+
+use braillix::canvas::{geometry::Circle, Canvas, Style};
+use braillix_ratatui::animation::{Animation, AnimationState, KeyCode, KeyEvent};
+
+const RADIUS: i32 = 6;
+
+/// A circle that moves with the arrow keys.
+#[derive(Default)]
+struct State {
+    x: i32,
+    y: i32,
+}
+
+impl AnimationState for State {
+    fn update(&mut self, _delta: Duration) {}
+
+    fn paint(&self, canvas: &mut Canvas) {
+        canvas.clear();
+
+        let (cx, cy) = canvas.rel(0.5, 0.5);
+        let center = (cx + self.x, cy + self.y);
+        canvas.draw(Circle::new(center, RADIUS), Style::outlined());
+    }
+
+    fn on_key(&mut self, event: KeyEvent) {
+        match event.code {
+            KeyCode::Left => self.x -= 2,
+            KeyCode::Right => self.x += 2,
+            KeyCode::Up => self.y -= 2,
+            KeyCode::Down => self.y += 2,
+            _ => {}
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut term = ratatui::init();
+    let res = Animation::new(&mut term, State::default())?.run(60.0);
+    ratatui::restore();
+    res
+}
@@ -6,7 +6,7 @@ use std::{
 
 use braillix::canvas::{
     geometry::{Line, Tri},
-    Canvas, Style,
+    Affine2, Canvas, Style,
 };
 use braillix_ratatui::animation::{Animation, AnimationState};
 
@@ -29,34 +29,29 @@ impl AnimationState for State {
             (-1.0, 0.0),
             (FRAC_PI_6).sin_cos(),
             (5.0 * FRAC_PI_6).sin_cos(),
-        ];
+        ]
+        .map(|(y, x)| (x, y));
 
         let (dw, dh) = canvas.dot_size();
-        let center = ((dw / 2) as f64, (dh / 2) as f64);
+        let center = canvas.rel(0.5, 0.5);
         let tri_size = dw.min(dh) as f64 * 0.4;
 
-        let (sin_theta, cos_theta) = self.theta.sin_cos();
-        let transformed_points: Vec<_> = verts_around_origin
-            .iter()
-            .map(|(y, x)| {
-                let tx = tri_size * (x * cos_theta - y * sin_theta);
-                let ty = tri_size * (y * cos_theta + x * sin_theta);
+        let transform = Affine2::scale(tri_size, tri_size)
+            .then(Affine2::rotate(self.theta))
+            .then(Affine2::translate(center.0 as f64, center.1 as f64));
 
-                (center.0 + tx, center.1 + ty)
-            })
-            .collect();
-
-        for &p in transformed_points.iter() {
-            canvas.draw(Line::new(center, p), Style::outlined());
+        for &p in verts_around_origin.iter() {
+            canvas.draw_transformed(Line::new((0.0, 0.0), p), Style::outlined(), transform);
         }
 
-        canvas.draw(
+        canvas.draw_transformed(
             Tri::new(
-                transformed_points[0],
-                transformed_points[1],
-                transformed_points[2],
+                verts_around_origin[0],
+                verts_around_origin[1],
+                verts_around_origin[2],
             ),
             Style::outlined(),
+            transform,
         );
     }
 }
@@ -1,11 +1,20 @@
 use std::{
-    io,
+    io::{self, Stdout},
+    mem,
     time::{Duration, Instant},
 };
 
-use braillix::canvas::Canvas;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use ratatui::{layout::Size, DefaultTerminal};
+use braillix::canvas::{Canvas, Style};
+pub use crossterm::event::{Event, KeyCode, KeyEvent, MouseEvent};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, KeyEventKind, KeyModifiers},
+    execute,
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    layout::Size,
+    Terminal,
+};
 
 use crate::ToWidget;
 
@@ -17,82 +26,745 @@ pub trait AnimationState {
     fn update(&mut self, delta: Duration);
 
     /// `paint` is called before drawing to the terminal to
-    /// get the canvas ready for display.
+    /// get the canvas ready for display. With the default
+    /// `Buffering::Double`, the canvas starts out blank every time.
     fn paint(&self, canvas: &mut Canvas);
+
+    /// `on_mouse` is called for every mouse event while the animation runs.
+    /// Terminals only report mouse events with mouse capture on (see
+    /// `Animation::with_mouse_capture`).
+    ///
+    /// `event.column` and `event.row` are in terminal character cells, so
+    /// the dots under the cursor start at `(column * 2, row * 4)` on the
+    /// canvas.
+    fn on_mouse(&mut self, _event: MouseEvent) {}
+
+    /// `on_key` is called for every key press that the `Animation` doesn't
+    /// handle itself (i.e. anything but the quit, pause, and FPS overlay
+    /// keys).
+    fn on_key(&mut self, _event: KeyEvent) {}
+
+    /// `on_resize` is called after the terminal is resized, with the new
+    /// size of the canvas in dots. The next `paint` draws at this size.
+    fn on_resize(&mut self, _width: usize, _height: usize) {}
+
+    /// `desired_fps` is checked every frame, and returning `Some` overrides
+    /// the FPS passed to `Animation::run` until it returns `None` again.
+    /// Values that aren't finite and positive are ignored.
+    fn desired_fps(&self) -> Option<f64> {
+        None
+    }
+}
+
+/// Where an `Animation` gets its input events from. The default is
+/// `CrosstermInput`, which reads from the terminal; other sources can feed
+/// events from a test harness or a different terminal library. See
+/// `Animation::with_input`.
+pub trait InputSource {
+    /// Waits up to `timeout` for an event, returning whether one is ready
+    /// to `read`.
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool>;
+
+    /// Reads the next event. This is only called after `poll` returns
+    /// `true`.
+    fn read(&mut self) -> io::Result<Event>;
+}
+
+/// Reads input events from the terminal with crossterm.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrosstermInput;
+
+impl InputSource for CrosstermInput {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        event::poll(timeout)
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+}
+
+/// How an `Animation` paints its frames. See `Animation::with_buffering`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Buffering {
+    /// `paint` draws into a cleared back buffer, which is swapped with the
+    /// displayed frame once it is finished, so the displayed frame is never
+    /// partially painted.
+    #[default]
+    Double,
+    /// `paint` draws straight onto the displayed frame, which still holds
+    /// whatever was painted last time. This suits states that draw
+    /// incrementally instead of repainting everything.
+    Single,
 }
 
 /// A simple ratatui app that can be used for programs that just need
 /// to draw fullscreen based on some state that gets updated.
-pub struct Animation<'a, S: AnimationState> {
-    terminal: &'a mut DefaultTerminal,
+///
+/// Frames are drawn with `Terminal::draw`, so only the cells that changed
+/// since the previous frame are written to the terminal.
+pub struct Animation<'a, S: AnimationState, B: Backend = CrosstermBackend<Stdout>> {
+    terminal: &'a mut Terminal<B>,
+    input: Box<dyn InputSource>,
     canvas: Canvas,
+    back_buffer: Option<Canvas>,
+    buffering: Buffering,
+    fixed_timestep: Option<Duration>,
+    accumulated: Duration,
     state: S,
+    quit_keys: Vec<KeyCode>,
+    pause_key: Option<KeyCode>,
+    fps_key: Option<KeyCode>,
+    mouse_capture: bool,
     quit_requested: bool,
     paused: bool,
+    show_fps: bool,
+    frame_time: Duration,
+    last_delta: Duration,
 }
 
-impl<'a, S: AnimationState> Animation<'a, S> {
+impl<'a, S: AnimationState, B: Backend> Animation<'a, S, B> {
+    /// The most `update` calls a single tick makes with a fixed timestep.
+    pub const MAX_FIXED_STEPS: u32 = 8;
+
     /// Create a new `Animation` with a terminal reference and an
     /// initial value for the state.
-    pub fn new(terminal: &'a mut DefaultTerminal, initial_state: S) -> io::Result<Self> {
+    pub fn new(terminal: &'a mut Terminal<B>, initial_state: S) -> io::Result<Self> {
         let Size { width, height } = terminal.size()?;
+        let canvas = Canvas::with_output_size(width as usize, height as usize);
+
         Ok(Self {
             terminal,
-            canvas: Canvas::with_output_size(width as usize, height as usize),
+            input: Box::new(CrosstermInput),
+            canvas,
+            back_buffer: None,
+            buffering: Buffering::default(),
+            fixed_timestep: None,
+            accumulated: Duration::ZERO,
             state: initial_state,
+            quit_keys: vec![KeyCode::Char('q'), KeyCode::Esc],
+            pause_key: Some(KeyCode::Char(' ')),
+            fps_key: None,
+            mouse_capture: false,
             quit_requested: false,
             paused: false,
+            show_fps: false,
+            frame_time: Duration::ZERO,
+            last_delta: Duration::ZERO,
         })
     }
 
-    /// Run the animation at the desired FPS. Listens for `Q`, `Esc`,
-    /// and `ctrl-c` to quit. `Space` and `p` toggle pause.
+    /// Replaces the keys that quit the animation, which are `q` and `Esc` by
+    /// default. `ctrl-c` always quits, even if `keys` is empty.
+    pub fn with_quit_keys(mut self, keys: Vec<KeyCode>) -> Self {
+        self.quit_keys = keys;
+        self
+    }
+
+    /// Sets the key that pauses and resumes the animation (see
+    /// `set_paused`) while running, which is `Space` by default. With
+    /// `None`, every key but the quit and FPS keys reaches
+    /// `AnimationState::on_key`.
+    pub fn with_pause_key(mut self, key: Option<KeyCode>) -> Self {
+        self.pause_key = key;
+        self
+    }
+
+    /// Sets a key that toggles the FPS overlay (see `show_fps`) while
+    /// running. There is none by default, so the key reaches
+    /// `AnimationState::on_key` instead.
+    pub fn with_fps_key(mut self, key: Option<KeyCode>) -> Self {
+        self.fps_key = key;
+        self
+    }
+
+    /// Sets whether `run` turns on mouse capture, so that the terminal
+    /// reports mouse events to `AnimationState::on_mouse`. It is off by
+    /// default.
+    ///
+    /// Mouse capture is turned on and off by writing crossterm commands to
+    /// stdout, so only enable it when the terminal is on stdout.
+    pub fn with_mouse_capture(mut self, capture: bool) -> Self {
+        self.mouse_capture = capture;
+        self
+    }
+
+    /// Replaces where input events come from, which is the terminal (see
+    /// `CrosstermInput`) by default.
+    pub fn with_input(mut self, input: impl InputSource + 'static) -> Self {
+        self.input = Box::new(input);
+        self
+    }
+
+    /// Sets how frames are painted, which is `Buffering::Double` by
+    /// default.
+    pub fn with_buffering(mut self, buffering: Buffering) -> Self {
+        self.buffering = buffering;
+        self.back_buffer = None;
+        self
+    }
+
+    /// Switches to a fixed timestep: instead of passing the measured time
+    /// since the last tick to `update`, elapsed time is accumulated and
+    /// `update` is called with exactly `dt` as many times as fits, carrying
+    /// the remainder over to the next tick. This keeps simulations
+    /// reproducible and stops a stalled frame from making them jump.
+    ///
+    /// At most `MAX_FIXED_STEPS` updates run per tick; any time beyond that
+    /// is dropped, so the animation slows down rather than falling further
+    /// and further behind. By default, the timestep is variable.
+    ///
+    /// # Panics
+    /// This function panics if `dt` is zero.
+    pub fn with_fixed_timestep(mut self, dt: Duration) -> Self {
+        assert!(!dt.is_zero(), "fixed timestep must be nonzero");
+        self.fixed_timestep = Some(dt);
+        self.accumulated = Duration::ZERO;
+        self
+    }
+
+    /// Pauses or resumes the animation. While paused, `update` is not
+    /// called but `paint` still runs every frame, and no time passes as far
+    /// as the state is concerned.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Checks whether the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Shows or hides an overlay in the top-left corner with the measured
+    /// frames per second and the `delta` of the last tick. The FPS comes from
+    /// the actual time between frames, not the requested rate.
+    pub fn show_fps(&mut self, show: bool) {
+        self.show_fps = show;
+    }
+
+    /// Advances the state by `delta` and paints it onto the canvas, without
+    /// touching the terminal. `run` calls this once per tick, and it can be
+    /// called directly to drive an animation by hand, e.g. in tests or when
+    /// writing frames somewhere other than a terminal. The state is updated
+    /// even if the animation is paused.
+    ///
+    /// With a fixed timestep (see `with_fixed_timestep`), `delta` is
+    /// accumulated and `update` is called zero or more times with the
+    /// fixed step instead.
+    pub fn tick(&mut self, delta: Duration) {
+        self.last_delta = delta;
+        match self.fixed_timestep {
+            Some(dt) => {
+                self.accumulated += delta;
+                let mut steps = 0;
+                while self.accumulated >= dt && steps < Self::MAX_FIXED_STEPS {
+                    self.state.update(dt);
+                    self.accumulated -= dt;
+                    steps += 1;
+                }
+                if steps == Self::MAX_FIXED_STEPS {
+                    self.accumulated = self.accumulated.min(dt);
+                }
+            }
+            None => self.state.update(delta),
+        }
+        self.paint();
+    }
+
+    /// Gets the canvas holding the last painted frame.
+    pub fn frame(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    /// Gets the last painted frame as braille text. See `frame`.
+    pub fn snapshot(&self) -> String {
+        self.canvas.to_string()
+    }
+
+    /// Run the animation at the desired FPS. Listens for the quit keys (see
+    /// `with_quit_keys`) and `ctrl-c` to quit. The pause key (`Space` unless
+    /// changed) toggles pause (see `with_pause_key`), and the FPS key, if any, toggles the FPS
+    /// overlay (see `with_fps_key`).
+    ///
+    /// Mouse events are passed to `AnimationState::on_mouse`. If mouse
+    /// capture is on (see `with_mouse_capture`), it is enabled while running.
     pub fn run(&mut self, fps: f64) -> io::Result<()> {
-        let tick_rate = Duration::from_secs_f64(1.0 / fps);
+        if !self.mouse_capture {
+            return self.run_loop(fps);
+        }
+
+        execute!(io::stdout(), EnableMouseCapture)?;
+        let result = self.run_loop(fps);
+        let disabled = execute!(io::stdout(), DisableMouseCapture);
+        result.and(disabled)
+    }
+
+    fn run_loop(&mut self, fps: f64) -> io::Result<()> {
         let mut last_tick = Instant::now();
+        let mut last_frame_at = Instant::now();
+        self.terminal.hide_cursor()?;
 
         loop {
+            self.frame_time = last_frame_at.elapsed();
+            last_frame_at = Instant::now();
+
+            let tick_rate = self.tick_rate(fps);
             let timeout = tick_rate.saturating_sub(last_tick.elapsed());
-            if event::poll(timeout)? {
-                if let Event::Key(evt) = event::read()? {
-                    if evt.kind == KeyEventKind::Press {
-                        self.handle_keypress(evt);
-                    }
-                }
+            if self.input.poll(timeout)? {
+                let event = self.input.read()?;
+                self.handle_event(event);
             }
 
             if self.quit_requested {
                 return Ok(());
             }
 
-            if self.paused {
+            if !self.paused && last_tick.elapsed() >= tick_rate {
+                self.tick(last_tick.elapsed());
                 last_tick = Instant::now();
-                continue;
+            } else {
+                self.paint();
+                if self.paused {
+                    // Keep the timer frozen so resuming doesn't deliver one
+                    // huge `delta` covering the whole pause.
+                    last_tick = Instant::now();
+                }
             }
 
-            self.state.paint(&mut self.canvas);
-            self.terminal
-                .draw(|f| f.render_widget(self.canvas.widget(), f.area()))?;
+            self.render_frame()?;
+        }
+    }
+
+    /// Gets the time between ticks, preferring the state's desired FPS over
+    /// the one passed to `run`.
+    fn tick_rate(&self, fps: f64) -> Duration {
+        let fps = self
+            .state
+            .desired_fps()
+            .filter(|f| f.is_finite() && *f > 0.0)
+            .unwrap_or(fps);
+        Duration::from_secs_f64(1.0 / fps)
+    }
 
-            if last_tick.elapsed() >= tick_rate {
-                self.state.update(last_tick.elapsed());
-                last_tick = Instant::now();
+    /// Paints the state onto the canvas, along with the FPS overlay if it is
+    /// shown.
+    fn paint(&mut self) {
+        if self.buffering == Buffering::Double {
+            // Swap in a blank canvas, keeping the previous frame intact.
+            let mut back = match self.back_buffer.take() {
+                Some(back) if back.dot_size() == self.canvas.dot_size() => back,
+                _ => self.canvas.clone(),
+            };
+            back.clear();
+            self.back_buffer = Some(mem::replace(&mut self.canvas, back));
+        }
+
+        self.state.paint(&mut self.canvas);
+        if self.show_fps {
+            self.paint_fps_overlay();
+        }
+    }
+
+    /// Writes the canvas to the terminal.
+    fn render_frame(&mut self) -> io::Result<()> {
+        let widget = self.canvas.widget();
+        self.terminal
+            .draw(|frame| frame.render_widget(widget, frame.area()))?;
+        Ok(())
+    }
+
+    fn paint_fps_overlay(&mut self) {
+        let fps = match self.frame_time.as_secs_f64() {
+            0.0 => 0.0,
+            secs => 1.0 / secs,
+        };
+        let delta_ms = self.last_delta.as_secs_f64() * 1000.0;
+        let text = format!("{fps:.0} FPS\n{delta_ms:.1} MS");
+        self.canvas
+            .draw_text((1, 1), &text, Style::outlined().fill_off());
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(evt) if evt.kind == KeyEventKind::Press => {
+                self.handle_keypress(evt);
+            }
+            Event::Mouse(evt) => self.state.on_mouse(evt),
+            Event::Resize(width, height) => {
+                self.canvas.resize(width as usize * 2, height as usize * 4);
+                let (w, h) = self.canvas.dot_size();
+                self.state.on_resize(w, h);
             }
+            _ => {}
         }
     }
 
     fn handle_keypress(&mut self, event: KeyEvent) {
         match event.code {
-            KeyCode::Char('q') | KeyCode::Esc => {
+            KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.quit_requested = true;
             }
-            KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+            code if self.quit_keys.contains(&code) => {
                 self.quit_requested = true;
             }
-            KeyCode::Char(' ') | KeyCode::Char('p') => {
-                self.paused = !self.paused;
+            code if self.pause_key == Some(code) => {
+                self.set_paused(!self.paused);
             }
-            _ => {}
+            code if self.fps_key == Some(code) => {
+                self.show_fps(!self.show_fps);
+            }
+            _ => self.state.on_key(event),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use braillix::canvas::geometry::Line;
+    use crossterm::event::{MouseButton, MouseEventKind};
+    use ratatui::backend::TestBackend;
+
+    use super::*;
+
+    struct Dot {
+        x: usize,
+        fps: Option<f64>,
+    }
+
+    impl AnimationState for Dot {
+        fn update(&mut self, _delta: Duration) {}
+
+        fn paint(&self, canvas: &mut Canvas) {
+            canvas.clear();
+            canvas.draw(Line::new((self.x, 5), (self.x, 5)), Style::outlined());
+        }
+
+        fn on_mouse(&mut self, event: MouseEvent) {
+            self.x = event.column as usize * 2;
+        }
+
+        fn on_key(&mut self, event: KeyEvent) {
+            if event.code == KeyCode::Right {
+                self.x += 1;
+            }
+        }
+
+        fn desired_fps(&self) -> Option<f64> {
+            self.fps
+        }
+    }
+
+    #[test]
+    fn only_changed_cells_are_written() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+
+        animation.paint();
+        animation.render_frame().unwrap();
+        animation
+            .terminal
+            .backend()
+            .assert_buffer_lines(["⠀⠀⠀⠀⠀⠀⠀⠀", "⠀⠂⠀⠀⠀⠀⠀⠀", "⠀⠀⠀⠀⠀⠀⠀⠀"]);
+
+        // Wipe the backend so that only cells written by the next frame show up.
+        animation.terminal.backend_mut().clear().unwrap();
+        animation.state.x = 9;
+        animation.paint();
+        animation.render_frame().unwrap();
+        animation.terminal.backend().assert_buffer_lines([
+            "        ", //
+            " ⠀  ⠐   ", //
+            "        ", //
+        ]);
+    }
+
+    #[test]
+    fn tick_and_snapshot() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 2)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+        assert_eq!(animation.snapshot(), "⠀⠀⠀⠀\n⠀⠀⠀⠀");
+
+        animation.tick(Duration::from_millis(16));
+        assert_eq!(animation.snapshot(), "⠀⠀⠀⠀\n⠀⠂⠀⠀");
+
+        animation.state.x = 7;
+        animation.tick(Duration::from_millis(16));
+        assert_eq!(animation.snapshot(), "⠀⠀⠀⠀\n⠀⠀⠀⠐");
+        assert!(animation.frame().is_set((7, 5)));
+
+        // Ticking never writes to the terminal.
+        animation
+            .terminal
+            .backend()
+            .assert_buffer_lines(["    ", "    "]);
+    }
+
+    /// Draws one more dot each frame, without clearing.
+    struct Trail {
+        frames: usize,
+    }
+
+    impl AnimationState for Trail {
+        fn update(&mut self, _delta: Duration) {
+            self.frames += 1;
         }
+
+        fn paint(&self, canvas: &mut Canvas) {
+            canvas.plot((self.frames, 0), Style::MAX_BRIGHTNESS);
+        }
+    }
+
+    #[test]
+    fn buffering() {
+        let tick = Duration::from_millis(16);
+
+        // Each double-buffered frame starts blank, and the one it replaces
+        // is kept intact as the back buffer.
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Trail { frames: 0 }).unwrap();
+        animation.tick(tick);
+        animation.tick(tick);
+        assert_eq!(animation.snapshot(), "⠀⠁⠀⠀");
+        let back = animation.back_buffer.as_ref().unwrap();
+        assert_eq!(back.to_string(), "⠈⠀⠀⠀");
+
+        // Single buffering paints over the previous frame.
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Trail { frames: 0 })
+            .unwrap()
+            .with_buffering(Buffering::Single);
+        animation.tick(tick);
+        animation.tick(tick);
+        assert_eq!(animation.snapshot(), "⠈⠁⠀⠀");
+        assert!(animation.back_buffer.is_none());
+    }
+
+    /// Draws one more dot each frame straight onto the display.
+    struct RawTrail {
+        frames: usize,
+    }
+
+    impl AnimationState for RawTrail {
+        fn update(&mut self, _delta: Duration) {
+            self.frames += 1;
+        }
+
+        fn paint(&self, canvas: &mut Canvas) {
+            canvas.display_mut().set(self.frames, 0);
+        }
+    }
+
+    #[test]
+    fn display_mut_reaches_terminal() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut animation = Animation::new(&mut terminal, RawTrail { frames: 0 })
+            .unwrap()
+            .with_buffering(Buffering::Single);
+        for _ in 0..2 {
+            animation.tick(Duration::from_millis(16));
+            animation.render_frame().unwrap();
+        }
+        animation.terminal.backend().assert_buffer_lines(["⠈⠁⠀⠀"]);
+    }
+
+    #[test]
+    fn fixed_timestep() {
+        let ms = Duration::from_millis;
+
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Trail { frames: 0 })
+            .unwrap()
+            .with_fixed_timestep(ms(10));
+
+        // The remainder of each tick carries over.
+        animation.tick(ms(25));
+        assert_eq!(animation.state.frames, 2);
+        animation.tick(ms(4));
+        assert_eq!(animation.state.frames, 2);
+        animation.tick(ms(1));
+        assert_eq!(animation.state.frames, 3);
+
+        // A stall only runs so many updates, and the rest is dropped.
+        animation.tick(ms(1000));
+        assert_eq!(animation.state.frames, 3 + 8);
+        animation.tick(ms(0));
+        assert_eq!(animation.state.frames, 3 + 9);
+        animation.tick(ms(0));
+        assert_eq!(animation.state.frames, 3 + 9);
+    }
+
+    /// Plays back a fixed list of events.
+    struct Script(std::collections::VecDeque<Event>);
+
+    impl InputSource for Script {
+        fn poll(&mut self, _timeout: Duration) -> io::Result<bool> {
+            Ok(!self.0.is_empty())
+        }
+
+        fn read(&mut self) -> io::Result<Event> {
+            Ok(self.0.pop_front().expect("polled before reading"))
+        }
+    }
+
+    #[test]
+    fn custom_input() {
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let script = Script(
+            [
+                KeyCode::Right,
+                KeyCode::Right,
+                KeyCode::Right,
+                KeyCode::Char('q'),
+            ]
+            .map(press)
+            .into(),
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(4, 2)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None })
+            .unwrap()
+            .with_input(script);
+        animation.run(1000.0).unwrap();
+
+        assert_eq!(animation.state.x, 5);
+        animation
+            .terminal
+            .backend()
+            .assert_buffer_lines(["⠀⠀⠀⠀", "⠀⠀⠐⠀"]);
+    }
+
+    #[test]
+    fn mouse_events_reach_state() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+
+        animation.handle_event(Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 3,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        }));
+        assert_eq!(animation.state.x, 6);
+    }
+
+    #[test]
+    fn pause_toggle() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+        assert!(!animation.is_paused());
+
+        // Space pauses by default.
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        animation.handle_event(Event::Key(space));
+        assert!(animation.is_paused());
+        animation.handle_event(Event::Key(space));
+        assert!(!animation.is_paused());
+
+        // Without a pause key, Space is left to the state.
+        let mut animation = animation.with_pause_key(None);
+        animation.handle_event(Event::Key(space));
+        assert!(!animation.is_paused());
+
+        animation.set_paused(true);
+        assert!(animation.is_paused());
+    }
+
+    #[test]
+    fn state_overrides_fps() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+        assert_eq!(animation.tick_rate(10.0), Duration::from_millis(100));
+
+        animation.state.fps = Some(4.0);
+        assert_eq!(animation.tick_rate(10.0), Duration::from_millis(250));
+
+        animation.state.fps = Some(0.0);
+        assert_eq!(animation.tick_rate(10.0), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn custom_quit_keys() {
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None })
+            .unwrap()
+            .with_quit_keys(vec![KeyCode::F(10)]);
+
+        animation.handle_event(press(KeyCode::Char('q')));
+        animation.handle_event(press(KeyCode::Esc));
+        assert!(!animation.quit_requested);
+
+        animation.handle_event(press(KeyCode::F(10)));
+        assert!(animation.quit_requested);
+
+        // ctrl-c quits no matter what.
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None })
+            .unwrap()
+            .with_quit_keys(Vec::new());
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        animation.handle_event(Event::Key(ctrl_c));
+        assert!(animation.quit_requested);
+    }
+
+    #[test]
+    fn unhandled_keys_reach_state() {
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+
+        animation.handle_event(press(KeyCode::Right));
+        animation.handle_event(press(KeyCode::Right));
+        animation.handle_event(press(KeyCode::Left));
+        assert_eq!(animation.state.x, 4);
+    }
+
+    #[test]
+    fn resize_reallocates_canvas() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 2, fps: None }).unwrap();
+        animation.paint();
+        animation.render_frame().unwrap();
+
+        animation.terminal.backend_mut().resize(4, 2);
+        animation.handle_event(Event::Resize(4, 2));
+        assert_eq!(animation.canvas.dot_size(), (8, 8));
+
+        animation.paint();
+        animation.render_frame().unwrap();
+        animation
+            .terminal
+            .backend()
+            .assert_buffer_lines(["⠀⠀⠀⠀", "⠀⠂⠀⠀"]);
+    }
+
+    #[test]
+    fn fps_overlay() {
+        let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+        let mut animation = Animation::new(&mut terminal, Dot { x: 39, fps: None }).unwrap();
+        animation.frame_time = Duration::from_millis(20);
+        animation.last_delta = Duration::from_millis(20);
+
+        // There is no FPS key unless one is set.
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        animation.handle_event(press(KeyCode::Char('f')));
+        assert!(!animation.show_fps);
+
+        let mut animation = animation.with_fps_key(Some(KeyCode::Char('f')));
+        animation.handle_event(press(KeyCode::Char('f')));
+        animation.paint();
+        animation.render_frame().unwrap();
+
+        // "50 FPS" starts at (1, 1): the top of the 5 and the 0.
+        let display = animation.canvas.display();
+        assert!(display.is_set(1, 1) && display.is_set(3, 1));
+        assert!(display.is_set(5, 1) && display.is_set(7, 1));
+
+        animation.handle_event(press(KeyCode::Char('f')));
+        animation.paint();
+        animation.render_frame().unwrap();
+        assert!(!animation.canvas.display().is_set(1, 1));
     }
 }
@@ -1,8 +1,16 @@
 use braillix::{canvas::Canvas, display::Display};
-use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::Color,
+    widgets::{Block, Widget},
+};
 
 pub mod animation;
 
+/// The braille pattern with no dots raised.
+const BLANK: char = '\u{2800}';
+
 /// An adapter trait providing a `widget` method for `braillix` objects.
 pub trait ToWidget {
     type Output<'a>: Widget
@@ -33,41 +41,236 @@ pub trait ToWidget {
     fn widget(&self) -> Self::Output<'_>;
 }
 
-/// Newtype for rendering a `braillix::Display` as a ratatui widget.
-pub struct DisplayWidget<'a>(&'a Display);
-impl Widget for DisplayWidget<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let d_width = self.0.output_width() as u16;
-        let d_height = self.0.output_height() as u16;
+/// Creates a `Canvas` that exactly fills `area` when rendered: one braille
+/// character per terminal cell, so it is `2 * width` by `4 * height` dots.
+/// A zero-sized area gives an empty canvas, which can be drawn on and
+/// rendered without effect.
+///
+/// This is for sizing a canvas from a ratatui layout in a custom render
+/// loop; `Animation` does it for the whole terminal.
+pub fn canvas_for(area: Rect) -> Canvas {
+    Canvas::with_output_size(area.width as usize, area.height as usize)
+}
+
+/// Maps the fraction of a cell's dots that are set to a foreground color.
+type ColorFn<'a> = Box<dyn Fn(f64) -> Color + 'a>;
+
+/// Where a widget sits vertically in an area taller than it is. See
+/// `DisplayWidget::align`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    /// Pinned to the top of the area.
+    #[default]
+    Top,
+    /// Centered in the area.
+    Center,
+    /// Pinned to the bottom of the area.
+    Bottom,
+}
 
-        let render_area = area.intersection(Rect::new(area.left(), area.top(), d_width, d_height));
-        let rw = render_area.width as usize;
+/// Renders a `braillix::Display` as a ratatui widget.
+pub struct DisplayWidget<'a> {
+    display: &'a Display,
+    transparent: bool,
+    color: Option<ColorFn<'a>>,
+    alignment: (Alignment, VerticalAlignment),
+    scale: u16,
+    block: Option<Block<'a>>,
+}
+impl<'a> DisplayWidget<'a> {
+    /// Sets whether blank cells are skipped instead of being written as
+    /// `'⠀'`, leaving whatever is already in the buffer visible behind them.
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    /// Colors each cell by its coverage: `color` is given the fraction of
+    /// the cell's 8 dots that are set (from `0.0` to `1.0`) and returns the
+    /// foreground color for that cell. This makes denser regions stand out,
+    /// e.g. by mapping higher coverage to brighter colors.
+    pub fn color_by_coverage(mut self, color: impl Fn(f64) -> Color + 'a) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
 
-        for (line, row) in self.0.lines().zip(render_area.rows()) {
-            Line::from(line.chars().take(rw).collect::<String>()).render(row, buf);
+    /// Sets where the display sits when the area is larger than it. By
+    /// default it is pinned to the top-left corner, but it can be centered
+    /// (e.g. inside a bordered block) or pushed to the right or bottom.
+    /// A display larger than the area is always cropped on the right and
+    /// bottom.
+    pub fn align(mut self, horizontal: Alignment, vertical: VerticalAlignment) -> Self {
+        self.alignment = (horizontal, vertical);
+        self
+    }
+
+    /// Wraps the display in `block`, which is rendered around it. The
+    /// display is drawn in the block's inner area, so borders, titles, and
+    /// padding are left alone, and alignment is relative to the inner area.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Renders each braille character as a `factor`x`factor` block of
+    /// repeated characters, so a small display can fill a large terminal at
+    /// the cost of looking coarser. Only whole-number scaling is supported,
+    /// and a `factor` of 0 is treated as 1. The scaled display is aligned
+    /// and cropped to the area like an unscaled one.
+    pub fn scale(mut self, factor: u16) -> Self {
+        self.scale = factor.max(1);
+        self
+    }
+}
+impl DisplayWidget<'_> {
+    /// Renders the surrounding block, if any, returning the area inside it.
+    fn render_block(&mut self, area: Rect, buf: &mut Buffer) -> Rect {
+        match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
         }
     }
+
+    /// Gets the part of `area` covered by the display.
+    fn render_area(&self, area: Rect) -> Rect {
+        let scaled = |n: usize| {
+            u16::try_from(n)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(self.scale)
+        };
+        let d_width = scaled(self.display.output_width()).min(area.width);
+        let d_height = scaled(self.display.output_height()).min(area.height);
+
+        let (horizontal, vertical) = self.alignment;
+        let (spare_x, spare_y) = (area.width - d_width, area.height - d_height);
+        let x = match horizontal {
+            Alignment::Left => 0,
+            Alignment::Center => spare_x / 2,
+            Alignment::Right => spare_x,
+        };
+        let y = match vertical {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => spare_y / 2,
+            VerticalAlignment::Bottom => spare_y,
+        };
+
+        Rect::new(area.x + x, area.y + y, d_width, d_height)
+    }
+
+    /// Writes the given cells (in output coordinates) into `render_area`,
+    /// scaled up into blocks, skipping any that fall outside of it.
+    fn write_cells(
+        &self,
+        cells: impl Iterator<Item = (usize, usize, char)>,
+        render_area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let scale = self.scale as usize;
+        let bounds = (render_area.width as usize, render_area.height as usize);
+        for (x, y, c) in cells {
+            let (x, y) = (x.saturating_mul(scale), y.saturating_mul(scale));
+            for (x, y) in square(x, y, scale, bounds) {
+                let position = (render_area.x + x as u16, render_area.y + y as u16);
+                if let Some(cell) = buf.cell_mut(position) {
+                    cell.set_char(c);
+                    if let Some(color) = &self.color {
+                        cell.set_fg(color(coverage(c)));
+                    }
+                }
+            }
+        }
+    }
+}
+impl Widget for DisplayWidget<'_> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = self.render_block(area, buf);
+        let render_area = self.render_area(area);
+        let cells = self
+            .display
+            .cells_as_chars()
+            .filter(|&(_, _, c)| !(self.transparent && c == BLANK));
+        self.write_cells(cells, render_area, buf);
+    }
 }
+
 impl ToWidget for Display {
     type Output<'a> = DisplayWidget<'a>;
 
     fn widget(&self) -> Self::Output<'_> {
-        DisplayWidget(self)
+        DisplayWidget {
+            display: self,
+            transparent: false,
+            color: None,
+            alignment: Default::default(),
+            scale: 1,
+            block: None,
+        }
     }
 }
 
-/// Newtype for rendering a `braillix::Canvas` as a ratatui widget.
-pub struct CanvasWidget<'a>(&'a Canvas);
+/// Gets the positions in the `size`x`size` block with its top-left corner
+/// at `(x, y)`, leaving out any at or past `(width, height)`.
+fn square(
+    x: usize,
+    y: usize,
+    size: usize,
+    (width, height): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    let xs = x..x.saturating_add(size).min(width);
+    let ys = y..y.saturating_add(size).min(height);
+    ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+}
+
+/// Gets the fraction of dots raised in a braille character.
+fn coverage(c: char) -> f64 {
+    let dots = (c as u32).wrapping_sub(BLANK as u32) & 0xff;
+    dots.count_ones() as f64 / 8.0
+}
+
+/// Renders a `braillix::Canvas` as a ratatui widget.
+pub struct CanvasWidget<'a>(DisplayWidget<'a>);
+impl<'a> CanvasWidget<'a> {
+    /// Sets whether blank cells are skipped. See `DisplayWidget::transparent`.
+    pub fn transparent(self, transparent: bool) -> Self {
+        Self(self.0.transparent(transparent))
+    }
+
+    /// Colors each cell by its coverage. See
+    /// `DisplayWidget::color_by_coverage`.
+    pub fn color_by_coverage(self, color: impl Fn(f64) -> Color + 'a) -> Self {
+        Self(self.0.color_by_coverage(color))
+    }
+
+    /// Sets where the canvas sits in a larger area. See
+    /// `DisplayWidget::align`.
+    pub fn align(self, horizontal: Alignment, vertical: VerticalAlignment) -> Self {
+        Self(self.0.align(horizontal, vertical))
+    }
+
+    /// Wraps the canvas in a block. See `DisplayWidget::block`.
+    pub fn block(self, block: Block<'a>) -> Self {
+        Self(self.0.block(block))
+    }
+
+    /// Scales each character up into a block. See `DisplayWidget::scale`.
+    pub fn scale(self, factor: u16) -> Self {
+        Self(self.0.scale(factor))
+    }
+}
 impl Widget for CanvasWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        DisplayWidget(self.0.display()).render(area, buf);
+        self.0.render(area, buf);
     }
 }
 impl ToWidget for Canvas {
     type Output<'a> = CanvasWidget<'a>;
 
     fn widget(&self) -> Self::Output<'_> {
-        CanvasWidget(self)
+        CanvasWidget(self.display().widget())
     }
 }
 
@@ -98,4 +301,171 @@ mod tests {
         let expected = Buffer::with_lines(vec!["⣏"]);
         assert_eq!(small_buf, expected);
     }
+
+    #[test]
+    fn canvas_for() {
+        let area = Rect::new(3, 2, 10, 5);
+        let mut canvas = super::canvas_for(area);
+        assert_eq!(canvas.dot_size(), (20, 20));
+        assert_eq!(canvas.output_size(), (10, 5));
+
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (20, 20)),
+            Style::outlined(),
+        );
+        let mut buf = Buffer::empty(area);
+        canvas.widget().render(area, &mut buf);
+        assert_eq!(buf[(3, 2)].symbol(), "⡏");
+        assert_eq!(buf[(12, 6)].symbol(), "⣸");
+
+        for area in [Rect::new(1, 1, 0, 4), Rect::new(1, 1, 4, 0)] {
+            let mut empty = super::canvas_for(area);
+            assert_eq!(
+                empty.output_size(),
+                (area.width as usize, area.height as usize)
+            );
+            empty.draw(
+                braillix::canvas::geometry::Circle::new((1, 1), 3),
+                Style::filled(),
+            );
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+            empty.widget().render(area, &mut buf);
+            assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 6, 6)));
+        }
+    }
+
+    #[test]
+    fn transparent() {
+        let mut canvas = Canvas::with_dot_size(6, 4);
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (2, 4)),
+            Style::filled(),
+        );
+
+        let mut buf = Buffer::with_lines(vec!["abc"]);
+        canvas.widget().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣿⠀⠀"]));
+
+        let mut buf = Buffer::with_lines(vec!["abc"]);
+        canvas.widget().transparent(true).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣿bc"]));
+    }
+
+    #[test]
+    fn align() {
+        let mut canvas = Canvas::with_dot_size(4, 4);
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (4, 4)),
+            Style::outlined(),
+        );
+
+        let render = |horizontal, vertical| {
+            let mut buf = Buffer::with_lines(vec!["....", "....", "...."]);
+            canvas
+                .widget()
+                .align(horizontal, vertical)
+                .render(buf.area, &mut buf);
+            buf
+        };
+
+        assert_eq!(
+            render(Alignment::Left, VerticalAlignment::Top),
+            Buffer::with_lines(vec!["⣏⣹..", "....", "...."])
+        );
+        assert_eq!(
+            render(Alignment::Center, VerticalAlignment::Center),
+            Buffer::with_lines(vec!["....", ".⣏⣹.", "...."])
+        );
+        assert_eq!(
+            render(Alignment::Right, VerticalAlignment::Bottom),
+            Buffer::with_lines(vec!["....", "....", "..⣏⣹"])
+        );
+
+        // Too small an area crops the display as usual.
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        canvas
+            .widget()
+            .align(Alignment::Right, VerticalAlignment::Bottom)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣏"]));
+    }
+
+    #[test]
+    fn block() {
+        let mut canvas = Canvas::with_dot_size(4, 4);
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (4, 4)),
+            Style::filled(),
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        canvas
+            .widget()
+            .block(Block::bordered())
+            .align(Alignment::Right, VerticalAlignment::Top)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["┌────┐", "│  ⣿⣿│", "└────┘"]));
+    }
+
+    #[test]
+    fn scale() {
+        let mut canvas = Canvas::with_dot_size(4, 4);
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (4, 4)),
+            Style::outlined(),
+        );
+
+        let mut buf = Buffer::with_lines(vec!["......", "......", "......"]);
+        canvas.widget().scale(2).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣏⣏⣹⣹..", "⣏⣏⣹⣹..", "......"]));
+
+        // Scaling happens before alignment and cropping.
+        let mut buf = Buffer::with_lines(vec!["...", "..."]);
+        canvas
+            .widget()
+            .scale(3)
+            .align(Alignment::Right, VerticalAlignment::Top)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣏⣏⣏", "⣏⣏⣏"]));
+
+        // Huge scales only visit the cells in the area.
+        let mut buf = Buffer::with_lines(vec!["..", ".."]);
+        canvas.widget().scale(u16::MAX).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⣏⣏", "⣏⣏"]));
+
+        // Displays wider than u16::MAX cells still fill the area.
+        let wide = Display::with_output_size(u16::MAX as usize + 2, 1);
+        let mut buf = Buffer::with_lines(vec!["...."]);
+        wide.widget().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["⠀⠀⠀⠀"]));
+    }
+
+    #[test]
+    fn color_by_coverage() {
+        let mut canvas = Canvas::with_dot_size(6, 4);
+        canvas.draw(
+            braillix::canvas::geometry::Rect::new((0, 0), (2, 4)),
+            Style::filled(),
+        );
+        canvas.draw(
+            braillix::canvas::geometry::Line::new((2, 0), (3, 0)),
+            Style::outlined(),
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        canvas
+            .widget()
+            .color_by_coverage(|c| Color::Rgb(0, (c * 255.0) as u8, 0))
+            .render(buf.area, &mut buf);
+
+        let greens: Vec<_> = (0..3).map(|x| buf[(x, 0)].fg).collect();
+        assert_eq!(
+            greens,
+            vec![
+                Color::Rgb(0, 255, 0),
+                Color::Rgb(0, 63, 0),
+                Color::Rgb(0, 0, 0)
+            ]
+        );
+    }
 }